@@ -10,6 +10,7 @@
 //! - **Custom Node Implementation**: Users can define their own nodes with custom computation logic by using the [`node`] macro.
 //! - **Concurrency Support**: Nodes that can be computed independently are executed in parallel, enhancing performance.
 //! - **Cache Optimization**: The graph automatically caches intermediate results to avoid redundant computations.
+//! - **Tracing**: With the `tracing` feature enabled, each node execution is recorded as a `compute_node` span of the `tracing` crate.
 //!
 //! ## Usage
 //!
@@ -157,12 +158,83 @@
 /// # assert_eq!(<Node as NodeFactory>::inputs(), <Node2 as NodeFactory>::inputs());
 /// # assert_eq!(<Node as NodeFactory>::outputs(), <Node2 as NodeFactory>::outputs());
 /// ```
+///
+/// ### Fallible nodes
+///
+/// Nodes marked with `fallible` return a `Result<T, E>` and their outputs are derived from `T`. An error is reported by
+/// [`ComputeGraph::compute`] as [`ComputeError::NodeExecutionFailed`]. `E` must be convertible into a [`NodeRunError`].
+/// Without `fallible`, a returned `Result` is a regular output value.
+///
+/// ```rust
+/// # use computegraph::{node, NodeFactory, ComputeGraph, ComputeError};
+/// #[derive(Debug, Clone)]
+/// struct Node {}
+///
+/// #[node(Node, fallible)]
+/// fn run(&self) -> Result<usize, String> {
+///     Err("something went wrong".to_string())
+/// }
+///
+/// let mut graph = ComputeGraph::new();
+/// let node = graph.add_node(Node {}, "node".to_string()).unwrap();
+/// let result = graph.compute(node.output());
+/// assert!(matches!(result, Err(ComputeError::NodeExecutionFailed { .. })));
+/// # assert_eq!(<Node as NodeFactory>::outputs()[0].1, std::any::TypeId::of::<usize>());
+/// ```
+///
+/// ### Default input values
+///
+/// An input parameter can be given a default value with `#[default(...)]`, which is used if the input is not connected.
+///
+/// ```rust
+/// # use computegraph::{node, NodeFactory, ComputeGraph};
+/// #[derive(Debug, Clone)]
+/// struct Repeat {}
+///
+/// #[node(Repeat)]
+/// fn run(&self, #[default("ab".to_string())] text: &String, #[default(2)] count: &usize) -> String {
+///     text.repeat(*count)
+/// }
+///
+/// let mut graph = ComputeGraph::new();
+/// let node = graph.add_node(Repeat {}, "repeat".to_string()).unwrap();
+/// assert_eq!(graph.compute(node.output()).unwrap(), "abab");
+/// ```
+///
+/// ### Generic nodes
+///
+/// Generic parameters are declared after the node name, including all bounds required by `run`.
+/// The generated handle is generic over the same parameters.
+///
+/// ```rust
+/// # use computegraph::{node, NodeFactory, ComputeGraph};
+/// #[derive(Debug, Clone)]
+/// struct Constant<T> {
+///     value: T,
+/// }
+///
+/// #[node(Constant<T: Clone + std::fmt::Debug + Send + Sync + 'static>)]
+/// fn run(&self) -> T {
+///     self.value.clone()
+/// }
+///
+/// let mut graph = ComputeGraph::new();
+/// let number = graph.add_node(Constant { value: 5 }, "number".to_string()).unwrap();
+/// let text = graph.add_node(Constant { value: "hi".to_string() }, "text".to_string()).unwrap();
+/// assert_eq!(graph.compute(number.output()).unwrap(), 5);
+/// assert_eq!(graph.compute(text.output()).unwrap(), "hi");
+/// ```
 pub use computegraph_macros::node;
 use dyn_clone::DynClone;
+use serde::{Deserialize, Serialize};
 use std::{
     any::{Any, TypeId},
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, PoisonError,
+    },
 };
 
 /// Represents a computation graph.
@@ -173,6 +245,35 @@ use std::{
 pub struct ComputeGraph {
     nodes: Vec<GraphNode>,
     edges: Vec<Connection>,
+    reject_cycles: bool,
+    inspections: Vec<Inspection>,
+}
+
+impl PartialEq for ComputeGraph {
+    /// Compares the structure of both graphs, see [`ComputeGraph::structurally_eq`].
+    fn eq(&self, other: &Self) -> bool {
+        self.structurally_eq(other)
+    }
+}
+
+/// An input port whose value is replaced during [`ComputeGraph::compute_fixpoint`].
+type Feedback<'a> = Option<(&'a InputPortUntyped, &'a dyn Fn() -> Box<dyn Any>)>;
+
+/// Copies values produced at an output port out of the graph, see [`ComputeGraph::inspect`].
+#[derive(Clone)]
+struct Inspection {
+    port: OutputPortUntyped,
+    sink: InspectionSink,
+}
+
+type InspectionSink = Arc<dyn Fn(&dyn Any) + Send + Sync>;
+
+impl fmt::Debug for Inspection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Inspection")
+            .field("port", &self.port)
+            .finish_non_exhaustive()
+    }
 }
 
 /// Errors that can occur when calling [`ComputeGraph::compute`].
@@ -191,8 +292,37 @@ pub enum ComputeError {
     CycleDetected,
     #[error("Output type mismatch when computing node {node:?}")]
     OutputTypeMismatch { node: NodeHandle },
+    #[error("Execution of node {node} failed: {source}")]
+    NodeExecutionFailed {
+        node: NodeHandle,
+        #[source]
+        source: NodeRunError,
+    },
+    #[error("Computation was cancelled")]
+    Cancelled,
+}
+
+/// Options for a single computation with [`ComputeGraph::compute_with`].
+#[derive(Default, Debug, Clone)]
+pub struct ComputationOptions {
+    /// Flag to abort the computation, for example when its result is no longer needed.
+    ///
+    /// The flag is checked before each node is run, so nodes do not need to be aware of
+    /// cancellation. Once set, the computation returns [`ComputeError::Cancelled`].
+    pub cancel: Option<Arc<AtomicBool>>,
+}
+
+impl ComputationOptions {
+    fn is_cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+    }
 }
 
+/// Error returned by a node when its computation fails, see [`ExecutableNode::run`].
+pub type NodeRunError = Box<dyn std::error::Error + Send + Sync>;
+
 /// Errors that can occur when connecting nodes with [`ComputeGraph::connect`].
 #[derive(thiserror::Error, Debug)]
 pub enum ConnectError {
@@ -209,6 +339,11 @@ pub enum ConnectError {
     InputPortNotFound(InputPortUntyped),
     #[error("Output port {0} not found")]
     OutputPortNotFound(OutputPortUntyped),
+    #[error("Connecting {from} to {to} would create a cycle")]
+    WouldCreateCycle {
+        from: OutputPortUntyped,
+        to: InputPortUntyped,
+    },
 }
 
 /// Errors that can occur during node removal through [`ComputeGraph::remove_node`].
@@ -218,6 +353,31 @@ pub enum RemoveNodeError {
     NodeNotFound(NodeHandle),
 }
 
+/// Errors that can occur when renaming a node with [`ComputeGraph::rename_node`].
+#[derive(thiserror::Error, Debug)]
+pub enum RenameNodeError {
+    #[error("Node with handle {0} not found")]
+    NodeNotFound(NodeHandle),
+    #[error("Node with the name {0} already exists")]
+    DuplicateName(String),
+}
+
+/// Errors that can occur when setting metadata with [`ComputeGraph::set_metadata`].
+#[derive(thiserror::Error, Debug)]
+pub enum MetadataError {
+    #[error("Node with handle {0} not found")]
+    NodeNotFound(NodeHandle),
+}
+
+/// Errors that can occur when inspecting an output with [`ComputeGraph::inspect`].
+#[derive(thiserror::Error, Debug)]
+pub enum InspectError {
+    #[error("Node {0} not found")]
+    NodeNotFound(NodeHandle),
+    #[error("Output port {0} not found")]
+    OutputPortNotFound(OutputPortUntyped),
+}
+
 /// Errors that can occur during disconnecting nodes with [`ComputeGraph::disconnect`].
 #[derive(thiserror::Error, Debug)]
 pub enum DisconnectError {
@@ -226,12 +386,61 @@ pub enum DisconnectError {
 }
 
 /// Errors that can occur when adding new nodes with [`ComputeGraph::add_node`].
-#[derive(thiserror::Error, Debug)]
+#[derive(thiserror::Error, Debug, Clone)]
 pub enum AddError {
     #[error("Node with the name {0} already exists")]
     DuplicateName(String),
 }
 
+/// Errors that can occur when assembling a graph with [`GraphBuilder::build`].
+#[derive(thiserror::Error, Debug)]
+pub enum BuildError {
+    #[error(transparent)]
+    Add(#[from] AddError),
+    #[error(transparent)]
+    Connect(#[from] ConnectError),
+}
+
+/// Problems in the structure of a graph, reported by [`ComputeGraph::validate`].
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum GraphValidationError {
+    #[error("Node {0} not found")]
+    NodeNotFound(NodeHandle),
+    #[error("Input port {0} not found")]
+    InputPortNotFound(InputPortUntyped),
+    #[error("Output port {0} not found")]
+    OutputPortNotFound(OutputPortUntyped),
+    #[error("Type mismatch when connecting {from} to {to}")]
+    TypeMismatch {
+        from: OutputPortUntyped,
+        to: InputPortUntyped,
+    },
+    #[error("Cycle detected between nodes {0:?}")]
+    CycleDetected(Vec<NodeHandle>),
+}
+
+/// Errors that can occur when converting a graph with [`ComputeGraph::to_schema`] and
+/// [`ComputeGraph::from_schema`].
+#[derive(thiserror::Error, Debug)]
+pub enum SchemaError {
+    #[error("Node type {0} is not registered")]
+    UnknownNodeType(String),
+    #[error("The type of node {0} is not registered")]
+    UnregisteredNode(String),
+    #[error("Port type {0} is not registered")]
+    UnregisteredType(String),
+    #[error("Ports of node {0} do not match the registered node type")]
+    PortMismatch(String),
+    #[error("Node {0} not found")]
+    NodeNotFound(String),
+    #[error("Port {port} not found in node {node}")]
+    PortNotFound { node: String, port: String },
+    #[error(transparent)]
+    Add(#[from] AddError),
+    #[error(transparent)]
+    Connect(#[from] ConnectError),
+}
+
 trait ClonableAny: Any + DynClone + fmt::Debug + Send + Sync {
     fn as_any(&self) -> &dyn Any;
     fn as_mut_any(&mut self) -> &mut dyn Any;
@@ -341,6 +550,8 @@ impl Metadata {
 pub struct DynamicNode {
     inputs: Vec<(&'static str, TypeId)>,
     outputs: Vec<(&'static str, TypeId)>,
+    input_type_names: Vec<&'static str>,
+    output_type_names: Vec<&'static str>,
     executable: Box<dyn ExecutableNode>,
 }
 
@@ -356,6 +567,91 @@ impl DynamicNode {
     pub fn outputs(&self) -> &[(&'static str, TypeId)] {
         &self.outputs
     }
+
+    /// Creates a node from port descriptions and a closure, for nodes that are only known at runtime.
+    ///
+    /// `run` receives the values of all inputs in the order of `inputs`, and must return the values of all
+    /// outputs in the order of `outputs`, just like [`ExecutableNode::run`].
+    ///
+    /// Port names are interned, the interned names can be retrieved with [`DynamicNode::inputs`] and
+    /// [`DynamicNode::outputs`] to build the ports for [`ComputeGraph::connect_untyped`]. Since interned
+    /// names are never freed, the number of distinct port names should be bounded.
+    ///
+    /// Since only the `TypeId` of each port is known, the type names of all ports are reported as
+    /// [`UNKNOWN_TYPE_NAME`].
+    #[must_use]
+    pub fn from_closure<F>(
+        inputs: Vec<(String, TypeId)>,
+        outputs: Vec<(String, TypeId)>,
+        run: F,
+    ) -> Self
+    where
+        F: Fn(&[&dyn Any]) -> Result<Vec<Box<dyn Any>>, NodeRunError>
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            input_type_names: vec![UNKNOWN_TYPE_NAME; inputs.len()],
+            output_type_names: vec![UNKNOWN_TYPE_NAME; outputs.len()],
+            inputs: inputs
+                .into_iter()
+                .map(|(name, ty)| (intern_port_name(name), ty))
+                .collect(),
+            outputs: outputs
+                .into_iter()
+                .map(|(name, ty)| (intern_port_name(name), ty))
+                .collect(),
+            executable: Box::new(ClosureNode { run: Arc::new(run) }),
+        }
+    }
+}
+
+/// The type name reported for ports whose type is not known, e.g. of nodes created with
+/// [`DynamicNode::from_closure`].
+pub const UNKNOWN_TYPE_NAME: &str = "<unknown>";
+
+/// Returns a `'static` version of `name`, reusing previously interned names.
+fn intern_port_name(name: String) -> &'static str {
+    static INTERNED: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+    let mut interned = INTERNED.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(name) = interned.get(name.as_str()) {
+        return name;
+    }
+    let name: &'static str = Box::leak(name.into_boxed_str());
+    interned.insert(name);
+    name
+}
+
+/// Node executing a closure, created with [`DynamicNode::from_closure`].
+#[derive(Clone)]
+struct ClosureNode<F> {
+    run: Arc<F>,
+}
+
+impl<F> fmt::Debug for ClosureNode<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClosureNode").finish_non_exhaustive()
+    }
+}
+
+impl<F> ExecutableNode for ClosureNode<F>
+where
+    F: Fn(&[&dyn Any]) -> Result<Vec<Box<dyn Any>>, NodeRunError> + Clone + Send + Sync + 'static,
+{
+    fn run(&self, input: &[Box<dyn Any>]) -> Result<Vec<Box<dyn Any>>, NodeRunError> {
+        let input: Vec<&dyn Any> = input.iter().map(AsRef::as_ref).collect();
+        (self.run)(&input)
+    }
+
+    fn partial_eq(&self, other: &dyn ExecutableNode) -> bool {
+        // Closures can not be compared, only copies of the same node are equal
+        let other: &dyn Any = other;
+        other
+            .downcast_ref::<Self>()
+            .is_some_and(|other| Arc::ptr_eq(&self.run, &other.run))
+    }
 }
 
 impl<T: NodeFactory + Clone + 'static> From<T> for DynamicNode {
@@ -363,11 +659,292 @@ impl<T: NodeFactory + Clone + 'static> From<T> for DynamicNode {
         Self {
             inputs: T::inputs(),
             outputs: T::outputs(),
+            input_type_names: T::input_type_names(),
+            output_type_names: T::output_type_names(),
             executable: Box::new(factory),
         }
     }
 }
 
+/// The conversion function of an adapter node inserted by [`ComputeGraph::connect_with`].
+type AdapterFn<A, B> = Arc<dyn Fn(&A) -> B + Send + Sync>;
+
+/// Node converting its input with a closure, inserted by [`ComputeGraph::connect_with`].
+struct AdapterNode<A, B> {
+    adapter: AdapterFn<A, B>,
+}
+
+impl<A, B> Clone for AdapterNode<A, B> {
+    fn clone(&self) -> Self {
+        Self {
+            adapter: self.adapter.clone(),
+        }
+    }
+}
+
+impl<A, B> fmt::Debug for AdapterNode<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AdapterNode")
+            .field("from", &std::any::type_name::<A>())
+            .field("to", &std::any::type_name::<B>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: 'static, B: 'static> ExecutableNode for AdapterNode<A, B> {
+    fn run(&self, input: &[Box<dyn Any>]) -> Result<Vec<Box<dyn Any>>, NodeRunError> {
+        let input = input
+            .first()
+            .and_then(|i| i.downcast_ref::<A>())
+            .ok_or_else(|| NodeRunError::from("input is missing or has the wrong type"))?;
+        Ok(vec![Box::new((self.adapter)(input))])
+    }
+
+    fn partial_eq(&self, other: &dyn ExecutableNode) -> bool {
+        let other: &dyn Any = other;
+        other
+            .downcast_ref::<Self>()
+            .is_some_and(|other| Arc::ptr_eq(&self.adapter, &other.adapter))
+    }
+}
+
+/// A registry of node and port types, used to convert graphs with [`ComputeGraph::to_schema`]
+/// and [`ComputeGraph::from_schema`].
+///
+/// Since the implementation of a node can not be serialized, each node type is registered with a
+/// constructor under a key, which is stored in the schema. Neither `TypeId` nor
+/// [`std::any::type_name`] are stable between builds, so keys and port type tags are chosen
+/// explicitly and should not change once schemas were saved.
+#[derive(Default, Debug, Clone)]
+pub struct NodeRegistry {
+    constructors: HashMap<String, fn() -> DynamicNode>,
+    node_keys: HashMap<TypeId, String>,
+    type_tags: HashMap<TypeId, String>,
+}
+
+impl NodeRegistry {
+    /// Creates a new, empty `NodeRegistry`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the node type `N` under `key`, constructed with `constructor`.
+    ///
+    /// Replaces any registration previously made for `N` or `key`.
+    pub fn register<N: ExecutableNode>(
+        &mut self,
+        key: impl Into<String>,
+        constructor: fn() -> DynamicNode,
+    ) {
+        let key = key.into();
+        self.node_keys.insert(TypeId::of::<N>(), key.clone());
+        self.constructors.insert(key, constructor);
+    }
+
+    /// Registers the port type `T` under `tag`.
+    ///
+    /// All types used by ports of registered nodes must be registered.
+    pub fn register_type<T: 'static>(&mut self, tag: impl Into<String>) {
+        self.type_tags.insert(TypeId::of::<T>(), tag.into());
+    }
+
+    /// Constructs a node of the type registered under `key`.
+    #[must_use]
+    pub fn construct(&self, key: &str) -> Option<DynamicNode> {
+        self.constructors.get(key).map(|constructor| constructor())
+    }
+
+    fn node_key(&self, node: &dyn ExecutableNode) -> Option<&String> {
+        let node: &dyn Any = node;
+        self.node_keys.get(&node.type_id())
+    }
+
+    fn type_tag(&self, ty: TypeId) -> Option<&String> {
+        self.type_tags.get(&ty)
+    }
+}
+
+/// Serializable description of the structure of a [`ComputeGraph`].
+///
+/// Created with [`ComputeGraph::to_schema`] and restored with [`ComputeGraph::from_schema`].
+/// Only the structure of the graph is stored, node implementations are reconstructed from a
+/// [`NodeRegistry`] and metadata is not preserved. Node types and port types are identified by
+/// the keys and tags they were registered with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphSchema {
+    pub nodes: Vec<NodeSchema>,
+    pub connections: Vec<ConnectionSchema>,
+    pub reject_cycles: bool,
+}
+
+/// Description of a single node in a [`GraphSchema`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeSchema {
+    pub name: String,
+    /// The key the node type is registered with, see [`NodeRegistry::register`].
+    pub node_type: String,
+    pub inputs: Vec<PortSchema>,
+    pub outputs: Vec<PortSchema>,
+}
+
+/// Description of a single input or output port in a [`NodeSchema`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PortSchema {
+    pub name: String,
+    /// The tag the port type is registered with, see [`NodeRegistry::register_type`].
+    pub type_tag: String,
+}
+
+/// Description of a single connection in a [`GraphSchema`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionSchema {
+    pub from_node: String,
+    pub from_output: String,
+    pub to_node: String,
+    pub to_input: String,
+}
+
+/// Assembles a [`ComputeGraph`], deferring all error handling to [`GraphBuilder::build`].
+///
+/// Nodes and connections are only recorded when added, so a larger graph can be described
+/// without handling errors at every step. [`GraphBuilder::build`] then reports all problems at once.
+///
+/// ```
+/// use computegraph::{node, GraphBuilder};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Constant(usize);
+///
+/// #[node(Constant)]
+/// fn run(&self) -> usize {
+///     self.0
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Double;
+///
+/// #[node(Double)]
+/// fn run(&self, value: &usize) -> usize {
+///     value * 2
+/// }
+///
+/// let mut builder = GraphBuilder::new();
+/// let constant = builder.add_node(Constant(21), "constant".to_string());
+/// let double = builder.add_node(Double, "double".to_string());
+/// builder.connect(constant.output(), double.input_value());
+///
+/// let graph = builder.build().unwrap();
+/// assert_eq!(graph.compute(double.output()).unwrap(), 42);
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct GraphBuilder {
+    /// Recorded nodes, or the error for a node with a duplicate name
+    nodes: Vec<Result<GraphNode, AddError>>,
+    connections: Vec<(OutputPortUntyped, InputPortUntyped)>,
+    reject_cycles: bool,
+}
+
+impl GraphBuilder {
+    /// Creates a new, empty `GraphBuilder`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the built graph rejects connections that would introduce a cycle.
+    ///
+    /// See [`ComputeGraph::set_reject_cycles`].
+    pub const fn reject_cycles(&mut self, reject_cycles: bool) -> &mut Self {
+        self.reject_cycles = reject_cycles;
+        self
+    }
+
+    /// Records a node to be added to the graph.
+    ///
+    /// The returned handle can be used for connections immediately, a duplicate name is only
+    /// reported by [`GraphBuilder::build`]. The handle of a node with a duplicate name is
+    /// detached (see [`NodeHandle::is_detached`]): it does not refer to any node, so connections
+    /// made through it are reported as [`ConnectError::NodeNotFound`].
+    pub fn add_node<N: NodeFactory + 'static>(
+        &mut self,
+        node_builder: N,
+        name: String,
+    ) -> N::Handle {
+        let duplicate = self.duplicate_error(&name);
+        let mut gnode = GraphNode::from_factory(node_builder, name);
+        gnode.handle.detached = duplicate.is_some();
+        let instance = N::create_handle(&gnode);
+        self.nodes.push(duplicate.map_or(Ok(gnode), Err));
+        instance
+    }
+
+    /// Records a dynamic node to be added to the graph.
+    ///
+    /// See [`GraphBuilder::add_node`].
+    pub fn add_node_dynamic(&mut self, node_builder: DynamicNode, name: String) -> NodeHandle {
+        let duplicate = self.duplicate_error(&name);
+        let mut gnode = GraphNode::from_dynamic(node_builder, name);
+        gnode.handle.detached = duplicate.is_some();
+        let instance = gnode.handle.clone();
+        self.nodes.push(duplicate.map_or(Ok(gnode), Err));
+        instance
+    }
+
+    /// Returns the error for a new node if `name` is already used by a recorded node.
+    fn duplicate_error(&self, name: &str) -> Option<AddError> {
+        self.nodes
+            .iter()
+            .flatten()
+            .any(|n| n.handle.node_name == name)
+            .then(|| AddError::DuplicateName(name.to_string()))
+    }
+
+    /// Records a connection between an output port and an input port.
+    pub fn connect<T>(&mut self, from: OutputPort<T>, to: InputPort<T>) -> &mut Self {
+        self.connect_untyped(from.port, to.port)
+    }
+
+    /// Records a connection between an output port and an input port, with types checked by
+    /// [`GraphBuilder::build`].
+    pub fn connect_untyped(&mut self, from: OutputPortUntyped, to: InputPortUntyped) -> &mut Self {
+        self.connections.push((from, to));
+        self
+    }
+
+    /// Builds the graph from all recorded nodes and connections.
+    ///
+    /// Nodes with a duplicate name and connections that can not be made are skipped, so that
+    /// the remaining ones can still be checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns all errors that [`ComputeGraph::add_node`] and [`ComputeGraph::connect_untyped`]
+    /// would have returned, in the order the nodes and connections were recorded.
+    pub fn build(self) -> Result<ComputeGraph, Vec<BuildError>> {
+        let mut graph = ComputeGraph::new();
+        graph.set_reject_cycles(self.reject_cycles);
+        let mut errors = vec![];
+
+        for gnode in self.nodes {
+            if let Err(err) = gnode.and_then(|gnode| graph.insert_node(gnode)) {
+                errors.push(err.into());
+            }
+        }
+        for (from, to) in self.connections {
+            if let Err(err) = graph.connect_untyped(from, to) {
+                errors.push(err.into());
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(graph)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
 impl ComputeGraph {
     /// Creates a new, empty `ComputeGraph`.
     #[must_use]
@@ -375,6 +952,26 @@ impl ComputeGraph {
         Self::default()
     }
 
+    /// Sets whether connections that would introduce a cycle are rejected.
+    ///
+    /// When enabled, [`ComputeGraph::connect`] and [`ComputeGraph::connect_untyped`] return
+    /// [`ConnectError::WouldCreateCycle`] instead of adding such a connection. This is useful for
+    /// interactive editing, where feedback should be immediate instead of failing with
+    /// [`ComputeError::CycleDetected`] during computation.
+    ///
+    /// Disabled by default, since the check has to search the graph on every connection.
+    pub const fn set_reject_cycles(&mut self, reject_cycles: bool) {
+        self.reject_cycles = reject_cycles;
+    }
+
+    /// Returns `true` if connections that would introduce a cycle are rejected.
+    ///
+    /// See [`ComputeGraph::set_reject_cycles`].
+    #[must_use]
+    pub const fn rejects_cycles(&self) -> bool {
+        self.reject_cycles
+    }
+
     /// Adds a node to the graph.
     ///
     /// # Arguments
@@ -394,19 +991,9 @@ impl ComputeGraph {
         node_builder: N,
         name: String,
     ) -> Result<N::Handle, AddError> {
-        if self.nodes.iter().any(|n| n.handle.node_name == name) {
-            return Err(AddError::DuplicateName(name));
-        }
-
-        let gnode = GraphNode {
-            inputs: N::inputs(),
-            outputs: N::outputs(),
-            node: Box::new(node_builder),
-            handle: NodeHandle { node_name: name },
-            metadata: Metadata::default(),
-        };
+        let gnode = GraphNode::from_factory(node_builder, name);
         let instance = N::create_handle(&gnode); // TODO: maybe this should not be defined by the impl
-        self.nodes.push(gnode);
+        self.insert_node(gnode)?;
         Ok(instance)
     }
 
@@ -422,128 +1009,733 @@ impl ComputeGraph {
     ///
     /// # Returns
     ///
-    /// A `Result` containing a `NodeHandle` for the newly added node if successful,
-    /// or an `AddError` if the operation fails.
+    /// A `Result` containing a `NodeHandle` for the newly added node if successful,
+    /// or an `AddError` if the operation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AddError::DuplicateName` if a node with the given name already exists in the graph.
+    pub fn add_node_dynamic(
+        &mut self,
+        node_builder: DynamicNode,
+        name: String,
+    ) -> Result<NodeHandle, AddError> {
+        self.insert_node(GraphNode::from_dynamic(node_builder, name))
+    }
+
+    /// Adds an already constructed node, rejecting duplicate names.
+    fn insert_node(&mut self, gnode: GraphNode) -> Result<NodeHandle, AddError> {
+        if self.nodes.iter().any(|n| n.handle == gnode.handle) {
+            return Err(AddError::DuplicateName(gnode.handle.node_name));
+        }
+        let instance = gnode.handle.clone();
+        self.nodes.push(gnode);
+        Ok(instance)
+    }
+
+    /// Connects an output port to an input port with runtime type checking.
+    ///
+    /// This function connects an output port to an input port in the graph.
+    /// When possible use the typed version of this function, [`ComputeGraph::connect`] that performs type checking at compile time.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The output port.
+    /// * `to` - The input port.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the connection or an error.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if:
+    /// - The input port is already connected.
+    /// - The nodes or ports do not exist.
+    /// - The types of the two ports do not match.
+    /// - The connection would create a cycle, if enabled with [`ComputeGraph::set_reject_cycles`].
+    pub fn connect_untyped(
+        &mut self,
+        from: OutputPortUntyped,
+        to: InputPortUntyped,
+    ) -> Result<Connection, ConnectError> {
+        // Check if the input port is already connected
+        if self.edges.iter().any(|e| e.to == to) {
+            return Err(ConnectError::InputPortAlreadyConnected { to, from });
+        }
+
+        // Find the nodes and ports
+        let from_node = self
+            .nodes
+            .iter()
+            .find(|n| n.handle == from.node)
+            .ok_or_else(|| ConnectError::NodeNotFound(from.node.clone()))?;
+        let to_node = self
+            .nodes
+            .iter()
+            .find(|n| n.handle == to.node)
+            .ok_or_else(|| ConnectError::NodeNotFound(to.node.clone()))?;
+
+        let from_port = from_node
+            .outputs
+            .iter()
+            .find(|o| o.0 == from.output_name)
+            .ok_or_else(|| ConnectError::OutputPortNotFound(from.clone()))?;
+
+        let to_port = to_node
+            .inputs
+            .iter()
+            .find(|i| i.0 == to.input_name)
+            .ok_or_else(|| ConnectError::InputPortNotFound(to.clone()))?;
+
+        // Check if the types of the ports match
+        if from_port.1 != to_port.1 {
+            return Err(ConnectError::TypeMismatch {
+                expected: to_port.1,
+                found: from_port.1,
+            });
+        }
+
+        if self.reject_cycles && self.is_reachable(&to.node, &from.node) {
+            return Err(ConnectError::WouldCreateCycle { from, to });
+        }
+
+        // Create the connection
+        let connection = Connection { from, to };
+        self.edges.push(connection.clone());
+
+        Ok(connection)
+    }
+
+    /// Connects an output port to an input port.
+    ///
+    /// This function connects an output port to an input port in the graph.
+    /// When the type is not known at compile time, use the untyped version of this function, [`ComputeGraph::connect_untyped`].
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The output port.
+    /// * `to` - The input port.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the connection or an error.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if:
+    /// - The input port is already connected.
+    /// - The nodes or ports do not exist.
+    /// - The connection would create a cycle, if enabled with [`ComputeGraph::set_reject_cycles`].
+    pub fn connect<T>(
+        &mut self,
+        from: OutputPort<T>,
+        to: InputPort<T>,
+    ) -> Result<Connection, ConnectError> {
+        self.connect_untyped(from.port, to.port)
+    }
+
+    /// Connects an output port to an input port of a different type through a conversion.
+    ///
+    /// A hidden adapter node is inserted between the two ports, which converts the value of `from`
+    /// using `adapter`. It behaves like any other node in the graph, and is listed by
+    /// [`ComputeGraph::iter_nodes`] with a name starting with `__adapter`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The output port.
+    /// * `to` - The input port.
+    /// * `adapter` - The function converting the output value to the input type.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the handle of the adapter node or an error.
+    /// Removing the adapter node with [`ComputeGraph::remove_node`] also removes both connections.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned under the same conditions as [`ComputeGraph::connect`].
+    /// In that case, the graph is left unchanged.
+    pub fn connect_with<A: 'static, B: 'static>(
+        &mut self,
+        from: OutputPort<A>,
+        to: InputPort<B>,
+        adapter: impl Fn(&A) -> B + Send + Sync + 'static,
+    ) -> Result<NodeHandle, ConnectError> {
+        let base_name = format!(
+            "__adapter.{}.{}",
+            to.port.node.node_name, to.port.input_name
+        );
+        let mut name = base_name.clone();
+        let mut suffix = 0;
+        while self.nodes.iter().any(|n| n.handle.node_name == name) {
+            suffix += 1;
+            name = format!("{base_name}.{suffix}");
+        }
+
+        let handle = NodeHandle::new(name);
+        self.nodes.push(GraphNode {
+            inputs: vec![("input", TypeId::of::<A>())],
+            outputs: vec![("output", TypeId::of::<B>())],
+            input_type_names: vec![std::any::type_name::<A>()],
+            output_type_names: vec![std::any::type_name::<B>()],
+            node: Box::new(AdapterNode {
+                adapter: Arc::new(adapter) as AdapterFn<A, B>,
+            }),
+            handle: handle.clone(),
+            metadata: Metadata::default(),
+        });
+
+        let result = self
+            .connect_untyped(
+                from.port,
+                InputPortUntyped {
+                    node: handle.clone(),
+                    input_name: "input",
+                },
+            )
+            .and_then(|_| {
+                self.connect_untyped(
+                    OutputPortUntyped {
+                        node: handle.clone(),
+                        output_name: "output",
+                    },
+                    to.port,
+                )
+            });
+        if let Err(err) = result {
+            // Undo the insertion of the adapter node and its connection
+            self.edges.retain(|c| c.to.node != handle);
+            self.nodes.retain(|n| n.handle != handle);
+            return Err(err);
+        }
+        Ok(handle)
+    }
+
+    /// Returns `true` if both graphs have the same structure.
+    ///
+    /// Two graphs are structurally equal if they contain nodes with the same names, port signatures
+    /// and [`ExecutableNode::partial_eq`] implementations, and the same connections. The order in which
+    /// nodes and connections were added, as well as metadata and settings like
+    /// [`ComputeGraph::set_reject_cycles`], are not compared.
+    #[must_use]
+    pub fn structurally_eq(&self, other: &Self) -> bool {
+        if self.nodes.len() != other.nodes.len() || self.edges.len() != other.edges.len() {
+            return false;
+        }
+        let nodes_eq = self.nodes.iter().all(|node| {
+            other.nodes.iter().any(|o| {
+                o.handle == node.handle
+                    && o.inputs == node.inputs
+                    && o.outputs == node.outputs
+                    && node.node.partial_eq(o.node.as_ref())
+            })
+        });
+        nodes_eq
+            && self.edges.iter().collect::<BTreeSet<_>>()
+                == other.edges.iter().collect::<BTreeSet<_>>()
+    }
+
+    /// Checks the whole graph for structural problems without computing anything.
+    ///
+    /// This verifies that all connections reference existing nodes and ports with matching types,
+    /// and that the graph contains no cycles. Unlike [`ComputeGraph::compute`], which stops at the first
+    /// problem on the path to the requested output, all problems in the graph are reported.
+    /// Each cycle is reported once, listing all nodes that are part of it.
+    ///
+    /// Unconnected input ports are not considered an error, since they only fail if their node is computed.
+    ///
+    /// # Errors
+    ///
+    /// Returns all problems found in the graph.
+    pub fn validate(&self) -> Result<(), Vec<GraphValidationError>> {
+        let mut errors = vec![];
+
+        for edge in &self.edges {
+            let from_node = self.get_node(&edge.from.node);
+            let to_node = self.get_node(&edge.to.node);
+            let (Some(from_node), Some(to_node)) = (from_node, to_node) else {
+                for (node, handle) in [(from_node, &edge.from.node), (to_node, &edge.to.node)] {
+                    if node.is_none() {
+                        errors.push(GraphValidationError::NodeNotFound(handle.clone()));
+                    }
+                }
+                continue;
+            };
+            let from_type = from_node.get_type_of_output(&edge.from);
+            let to_type = to_node.get_type_of_input(&edge.to);
+            match (from_type, to_type) {
+                (None, _) => {
+                    errors.push(GraphValidationError::OutputPortNotFound(edge.from.clone()));
+                }
+                (_, None) => {
+                    errors.push(GraphValidationError::InputPortNotFound(edge.to.clone()));
+                }
+                (Some(from_type), Some(to_type)) if from_type != to_type => {
+                    errors.push(GraphValidationError::TypeMismatch {
+                        from: edge.from.clone(),
+                        to: edge.to.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        // Topological sort, nodes that can not be sorted are part of or downstream of a cycle
+        let mut in_degree: HashMap<&NodeHandle, usize> =
+            self.nodes.iter().map(|n| (&n.handle, 0)).collect();
+        for edge in &self.edges {
+            if let Some(degree) = in_degree.get_mut(&edge.to.node) {
+                *degree += 1;
+            }
+        }
+        let mut ready: Vec<&NodeHandle> = in_degree
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(node, _)| *node)
+            .collect();
+        while let Some(node) = ready.pop() {
+            in_degree.remove(node);
+            for edge in self.edges.iter().filter(|e| &e.from.node == node) {
+                if let Some(degree) = in_degree.get_mut(&edge.to.node) {
+                    *degree -= 1;
+                    if *degree == 0 {
+                        ready.push(&edge.to.node);
+                    }
+                }
+            }
+        }
+
+        // Group the remaining nodes into strongly connected components, each of which is a cycle
+        let mut remaining: Vec<&NodeHandle> = in_degree.into_keys().collect();
+        remaining.sort();
+        let mut assigned = HashSet::new();
+        for &node in &remaining {
+            if assigned.contains(node) {
+                continue;
+            }
+            let component: Vec<NodeHandle> = remaining
+                .iter()
+                .filter(|&&other| {
+                    !assigned.contains(other)
+                        && self.is_reachable(node, other)
+                        && self.is_reachable(other, node)
+                })
+                .map(|&other| other.clone())
+                .collect();
+            let is_cycle = component.len() > 1
+                || self
+                    .edges
+                    .iter()
+                    .any(|e| &e.from.node == node && &e.to.node == node);
+            assigned.extend(component.iter().cloned());
+            if is_cycle {
+                errors.push(GraphValidationError::CycleDetected(component));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Describes the structure of the graph in a serializable form.
+    ///
+    /// See [`GraphSchema`] for what is stored.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the type of a node or a port is not registered in `registry`.
+    /// Nodes created with [`DynamicNode::from_closure`] or [`ComputeGraph::connect_with`] can
+    /// therefore not be described.
+    pub fn to_schema(&self, registry: &NodeRegistry) -> Result<GraphSchema, SchemaError> {
+        let port_schemas = |ports: &[(&'static str, TypeId)], type_names: &[&'static str]| {
+            ports
+                .iter()
+                .zip(type_names)
+                .map(|((name, ty), type_name)| {
+                    Ok(PortSchema {
+                        name: (*name).to_string(),
+                        type_tag: registry
+                            .type_tag(*ty)
+                            .ok_or_else(|| SchemaError::UnregisteredType((*type_name).to_string()))?
+                            .clone(),
+                    })
+                })
+                .collect::<Result<Vec<_>, SchemaError>>()
+        };
+
+        Ok(GraphSchema {
+            nodes: self
+                .nodes
+                .iter()
+                .map(|n| {
+                    Ok(NodeSchema {
+                        name: n.handle.node_name.clone(),
+                        node_type: registry
+                            .node_key(n.node.as_ref())
+                            .ok_or_else(|| {
+                                SchemaError::UnregisteredNode(n.handle.node_name.clone())
+                            })?
+                            .clone(),
+                        inputs: port_schemas(&n.inputs, &n.input_type_names)?,
+                        outputs: port_schemas(&n.outputs, &n.output_type_names)?,
+                    })
+                })
+                .collect::<Result<_, SchemaError>>()?,
+            connections: self
+                .edges
+                .iter()
+                .map(|e| ConnectionSchema {
+                    from_node: e.from.node.node_name.clone(),
+                    from_output: e.from.output_name.to_string(),
+                    to_node: e.to.node.node_name.clone(),
+                    to_input: e.to.input_name.to_string(),
+                })
+                .collect(),
+            reject_cycles: self.reject_cycles,
+        })
+    }
+
+    /// Describes the structure of the graph in the Graphviz DOT language, for debugging.
+    ///
+    /// Each node is labeled with its name and the names and types of its input and output ports,
+    /// each connection with the names of the ports it connects.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        use fmt::Write as _;
+
+        fn escape(s: &str) -> String {
+            s.replace('\\', "\\\\").replace('"', "\\\"")
+        }
+        let port_names = |ports: &[(&'static str, TypeId)], type_names: &[&'static str]| {
+            ports
+                .iter()
+                .zip(type_names)
+                .map(|(p, ty)| format!("{}: {}", escape(p.0), escape(ty)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let mut dot = String::from("digraph {\n");
+        for node in &self.nodes {
+            let name = escape(&node.handle.node_name);
+            // Writing to a `String` can not fail
+            let _ = writeln!(
+                dot,
+                "    \"{name}\" [shape=box, label=\"{name}\\ninputs: {}\\noutputs: {}\"];",
+                port_names(&node.inputs, &node.input_type_names),
+                port_names(&node.outputs, &node.output_type_names),
+            );
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [label=\"{} -> {}\"];",
+                escape(&edge.from.node.node_name),
+                escape(&edge.to.node.node_name),
+                escape(edge.from.output_name),
+                escape(edge.to.input_name),
+            );
+        }
+        dot.push('}');
+        dot
+    }
+
+    /// Restores a graph from a [`GraphSchema`], constructing all nodes with the given registry.
+    ///
+    /// Port types are taken from the registered nodes and checked against the type tags stored
+    /// in the schema, so a node whose ports changed is detected. Connections are type checked
+    /// just like with [`ComputeGraph::connect_untyped`].
     ///
     /// # Errors
     ///
-    /// Returns `AddError::DuplicateName` if a node with the given name already exists in the graph.
-    pub fn add_node_dynamic(
-        &mut self,
-        node_builder: DynamicNode,
-        name: String,
-    ) -> Result<NodeHandle, AddError> {
-        if self.nodes.iter().any(|n| n.handle.node_name == name) {
-            return Err(AddError::DuplicateName(name));
+    /// An error is returned if:
+    /// - A node type is not registered in `registry`.
+    /// - The names or types of the ports of a registered node differ from the ones stored in the schema.
+    /// - A connection references a node or port that does not exist.
+    /// - Nodes could not be added or connected, for example because of duplicate names.
+    pub fn from_schema(schema: &GraphSchema, registry: &NodeRegistry) -> Result<Self, SchemaError> {
+        let mut graph = Self::new();
+        graph.set_reject_cycles(schema.reject_cycles);
+
+        for node in &schema.nodes {
+            let dynamic_node = registry
+                .construct(&node.node_type)
+                .ok_or_else(|| SchemaError::UnknownNodeType(node.node_type.clone()))?;
+            let ports_match = |ports: &[(&'static str, TypeId)], schemas: &[PortSchema]| {
+                ports.len() == schemas.len()
+                    && ports.iter().zip(schemas).all(|(p, schema)| {
+                        p.0 == schema.name && registry.type_tag(p.1) == Some(&schema.type_tag)
+                    })
+            };
+            if !ports_match(dynamic_node.inputs(), &node.inputs)
+                || !ports_match(dynamic_node.outputs(), &node.outputs)
+            {
+                return Err(SchemaError::PortMismatch(node.name.clone()));
+            }
+            graph.add_node_dynamic(dynamic_node, node.name.clone())?;
         }
 
-        let gnode = GraphNode {
-            inputs: node_builder.inputs,
-            outputs: node_builder.outputs,
-            node: node_builder.executable,
-            handle: NodeHandle { node_name: name },
-            metadata: Metadata::default(),
-        };
+        for connection in &schema.connections {
+            let find_node = |name: &String| {
+                graph
+                    .nodes
+                    .iter()
+                    .find(|n| &n.handle.node_name == name)
+                    .ok_or_else(|| SchemaError::NodeNotFound(name.clone()))
+            };
+            let port_not_found = |node: &String, port: &String| SchemaError::PortNotFound {
+                node: node.clone(),
+                port: port.clone(),
+            };
+
+            let from_node = find_node(&connection.from_node)?;
+            let from_output = from_node
+                .outputs
+                .iter()
+                .find(|o| o.0 == connection.from_output)
+                .ok_or_else(|| port_not_found(&connection.from_node, &connection.from_output))?
+                .0;
+            let from = from_node.handle.clone().to_output_port(from_output);
+
+            let to_node = find_node(&connection.to_node)?;
+            let to_input = to_node
+                .inputs
+                .iter()
+                .find(|i| i.0 == connection.to_input)
+                .ok_or_else(|| port_not_found(&connection.to_node, &connection.to_input))?
+                .0;
+            let to = to_node.handle.clone().to_input_port(to_input);
 
-        let instance = gnode.handle.clone();
-        self.nodes.push(gnode);
-        Ok(instance)
+            graph.connect_untyped(from, to)?;
+        }
+
+        Ok(graph)
     }
 
-    /// Connects an output port to an input port with runtime type checking.
+    /// Checks if `target` can be reached from `start` by following connections downstream.
+    fn is_reachable(&self, start: &NodeHandle, target: &NodeHandle) -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            if node == target {
+                return true;
+            }
+            if visited.insert(node) {
+                stack.extend(
+                    self.edges
+                        .iter()
+                        .filter(|e| &e.from.node == node)
+                        .map(|e| &e.to.node),
+                );
+            }
+        }
+        false
+    }
+
+    /// Copies all nodes and connections of another graph into this graph.
     ///
-    /// This function connects an output port to an input port in the graph.
-    /// When possible use the typed version of this function, [`ComputeGraph::connect`] that performs type checking at compile time.
+    /// Each node is renamed to `{prefix}{name}`, connections between the copied nodes are preserved.
+    /// Use the returned map to connect the copied nodes with the rest of the graph.
     ///
     /// # Arguments
     ///
-    /// * `from` - The output port.
-    /// * `to` - The input port.
+    /// * `other` - The graph to merge into this graph.
+    /// * `prefix` - The prefix prepended to the name of each node of `other`.
     ///
     /// # Returns
     ///
-    /// A result containing the connection or an error.
+    /// A map from the handles of the nodes in `other` to the handles of the copied nodes.
     ///
     /// # Errors
     ///
-    /// An error is returned if:
-    /// - The input port is already connected.
-    /// - The nodes or ports do not exist.
-    /// - The types of the two ports do not match.
-    pub fn connect_untyped(
+    /// Returns `AddError::DuplicateName` if a prefixed name already exists in the graph.
+    /// In that case, the graph is left unchanged.
+    pub fn merge(
         &mut self,
-        from: OutputPortUntyped,
-        to: InputPortUntyped,
-    ) -> Result<Connection, ConnectError> {
-        // Check if the input port is already connected
-        if self.edges.iter().any(|e| e.to == to) {
-            return Err(ConnectError::InputPortAlreadyConnected { to, from });
-        }
-
-        // Find the nodes and ports
-        let from_node = self
-            .nodes
-            .iter()
-            .find(|n| n.handle == from.node)
-            .ok_or_else(|| ConnectError::NodeNotFound(from.node.clone()))?;
-        let to_node = self
+        other: Self,
+        prefix: &str,
+    ) -> Result<HashMap<NodeHandle, NodeHandle>, AddError> {
+        let handles: HashMap<NodeHandle, NodeHandle> = other
             .nodes
             .iter()
-            .find(|n| n.handle == to.node)
-            .ok_or_else(|| ConnectError::NodeNotFound(to.node.clone()))?;
-
-        let from_port = from_node
-            .outputs
-            .iter()
-            .find(|o| o.0 == from.output_name)
-            .ok_or_else(|| ConnectError::OutputPortNotFound(from.clone()))?;
+            .map(|n| {
+                let new_handle = NodeHandle::new(format!("{prefix}{}", n.handle.node_name));
+                (n.handle.clone(), new_handle)
+            })
+            .collect();
+        if let Some(duplicate) = handles
+            .values()
+            .find(|h| self.nodes.iter().any(|n| &n.handle == *h))
+        {
+            return Err(AddError::DuplicateName(duplicate.node_name.clone()));
+        }
 
-        let to_port = to_node
-            .inputs
-            .iter()
-            .find(|i| i.0 == to.input_name)
-            .ok_or_else(|| ConnectError::InputPortNotFound(to.clone()))?;
+        for mut node in other.nodes {
+            node.handle = handles[&node.handle].clone();
+            self.nodes.push(node);
+        }
+        for mut edge in other.edges {
+            edge.from.node = handles[&edge.from.node].clone();
+            edge.to.node = handles[&edge.to.node].clone();
+            self.edges.push(edge);
+        }
+        // Inspections always refer to an existing node, but a dangling one must not abort the merge
+        for mut inspection in other.inspections {
+            if let Some(handle) = handles.get(&inspection.port.node) {
+                inspection.port.node = handle.clone();
+                self.inspections.push(inspection);
+            }
+        }
+        Ok(handles)
+    }
 
-        // Check if the types of the ports match
-        if from_port.1 != to_port.1 {
-            return Err(ConnectError::TypeMismatch {
-                expected: to_port.1,
-                found: from_port.1,
+    /// Extracts the part of the graph required to compute `output`.
+    ///
+    /// The returned graph contains only the node of `output` and all nodes it depends on,
+    /// together with the connections between them. Computing `output` on it produces the same
+    /// result as on the original graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ComputeError::NodeNotFound` or `ComputeError::PortNotFound` if `output` does not exist.
+    pub fn subgraph(&self, output: &OutputPortUntyped) -> Result<Self, ComputeError> {
+        let output_node = self
+            .get_node(&output.node)
+            .ok_or_else(|| ComputeError::NodeNotFound(output.node.clone()))?;
+        if output_node.get_type_of_output(output).is_none() {
+            return Err(ComputeError::PortNotFound {
+                node: output.node.clone(),
+                port: output.clone(),
             });
         }
 
-        // Create the connection
-        let connection = Connection { from, to };
-        self.edges.push(connection.clone());
+        // Walk the graph upstream, starting at the requested node
+        let mut included = HashSet::new();
+        let mut stack = vec![&output.node];
+        while let Some(node) = stack.pop() {
+            if included.insert(node) {
+                stack.extend(
+                    self.edges
+                        .iter()
+                        .filter(|e| &e.to.node == node)
+                        .map(|e| &e.from.node),
+                );
+            }
+        }
 
-        Ok(connection)
+        Ok(Self {
+            nodes: self
+                .nodes
+                .iter()
+                .filter(|n| included.contains(&n.handle))
+                .cloned()
+                .collect(),
+            edges: self
+                .edges
+                .iter()
+                .filter(|e| included.contains(&e.to.node))
+                .cloned()
+                .collect(),
+            reject_cycles: self.reject_cycles,
+            inspections: self
+                .inspections
+                .iter()
+                .filter(|i| included.contains(&i.port.node))
+                .cloned()
+                .collect(),
+        })
     }
 
-    /// Connects an output port to an input port.
+    /// Copies every value produced at `port` into `sink`.
     ///
-    /// This function connects an output port to an input port in the graph.
-    /// When the type is not known at compile time, use the untyped version of this function, [`ComputeGraph::connect_untyped`].
+    /// Whenever the node of `port` is run during a computation, `sink` is set to a clone of the
+    /// produced value. This is intended for debugging, since values flowing between nodes can be
+    /// observed without changing the graph or what is computed.
+    ///
+    /// Inspections are removed together with their node. Multiple inspections of the same port are allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InspectError::NodeNotFound` or `InspectError::OutputPortNotFound` if `port` does not exist.
+    pub fn inspect<T: Clone + Send + 'static>(
+        &mut self,
+        port: OutputPort<T>,
+        sink: Arc<Mutex<Option<T>>>,
+    ) -> Result<(), InspectError> {
+        let node = self
+            .get_node(&port.port.node)
+            .ok_or_else(|| InspectError::NodeNotFound(port.port.node.clone()))?;
+        if node.get_type_of_output(&port.port).is_none() {
+            return Err(InspectError::OutputPortNotFound(port.port));
+        }
+        self.inspections.push(Inspection {
+            port: port.port,
+            sink: Arc::new(move |value| {
+                if let Some(value) = value.downcast_ref::<T>() {
+                    *sink.lock().unwrap_or_else(PoisonError::into_inner) = Some(value.clone());
+                }
+            }),
+        });
+        Ok(())
+    }
+
+    /// Removes all inspections added with [`ComputeGraph::inspect`].
+    pub fn clear_inspections(&mut self) {
+        self.inspections.clear();
+    }
+
+    /// Renames a node, keeping all of its connections.
+    ///
+    /// Since nodes are identified by their name, all existing handles and ports of the node become
+    /// invalid. Use the returned handle instead.
     ///
     /// # Arguments
     ///
-    /// * `from` - The output port.
-    /// * `to` - The input port.
+    /// * `from` - The handle of the node to rename.
+    /// * `to` - The new name of the node, must be unique for the whole graph.
     ///
     /// # Returns
     ///
-    /// A result containing the connection or an error.
+    /// The handle of the renamed node.
     ///
     /// # Errors
     ///
-    /// An error is returned if:
-    /// - The input port is already connected.
-    /// - The nodes or ports do not exist.
-    pub fn connect<T>(
+    /// An error is returned if the node does not exist or the new name is already used by another node.
+    pub fn rename_node(
         &mut self,
-        from: OutputPort<T>,
-        to: InputPort<T>,
-    ) -> Result<Connection, ConnectError> {
-        self.connect_untyped(from.port, to.port)
+        from: &NodeHandle,
+        to: String,
+    ) -> Result<NodeHandle, RenameNodeError> {
+        if !self.nodes.iter().any(|n| &n.handle == from) {
+            return Err(RenameNodeError::NodeNotFound(from.clone()));
+        }
+        let new_handle = NodeHandle::new(to);
+        if &new_handle == from {
+            return Ok(new_handle);
+        }
+        if self.nodes.iter().any(|n| n.handle == new_handle) {
+            return Err(RenameNodeError::DuplicateName(new_handle.node_name));
+        }
+
+        for node in self.nodes.iter_mut().filter(|n| &n.handle == from) {
+            node.handle = new_handle.clone();
+        }
+        for edge in &mut self.edges {
+            if &edge.from.node == from {
+                edge.from.node = new_handle.clone();
+            }
+            if &edge.to.node == from {
+                edge.to.node = new_handle.clone();
+            }
+        }
+        for inspection in self.inspections.iter_mut().filter(|i| &i.port.node == from) {
+            inspection.port.node = new_handle.clone();
+        }
+        Ok(new_handle)
     }
 
     /// Removes a node from the graph.
@@ -572,6 +1764,7 @@ impl ComputeGraph {
             return Err(RemoveNodeError::NodeNotFound(node_handle));
         }
         self.nodes.retain(|n| n.handle != node_handle);
+        self.inspections.retain(|i| i.port.node != node_handle);
 
         Ok(())
     }
@@ -618,8 +1811,24 @@ impl ComputeGraph {
     /// - A cycle is detected in the graph.
     /// - A error occurs during computation (e.g. type returned by the node does not match the expected type).
     pub fn compute_untyped(&self, output: OutputPortUntyped) -> Result<Box<dyn Any>, ComputeError> {
+        self.compute_untyped_with(output, &ComputationOptions::default())
+    }
+
+    /// Computes the result for a given output port with the given options, returning a boxed value.
+    ///
+    /// This function is the untyped version of [`ComputeGraph::compute_with`].
+    ///
+    /// # Errors
+    ///
+    /// An error is returned under the same conditions as [`ComputeGraph::compute_untyped`],
+    /// or if the computation was cancelled through [`ComputationOptions::cancel`].
+    pub fn compute_untyped_with(
+        &self,
+        output: OutputPortUntyped,
+        options: &ComputationOptions,
+    ) -> Result<Box<dyn Any>, ComputeError> {
         let mut visited = HashSet::new();
-        self.compute_recursive(output, &mut visited)
+        self.compute_recursive(output, &mut visited, options, None)
     }
 
     /// Computes the result for a given output port.
@@ -640,7 +1849,21 @@ impl ComputeGraph {
     /// - An input port of the node ar a dependency of the node are not connected.
     /// - A cycle is detected in the graph.
     pub fn compute<T: 'static>(&self, output: OutputPort<T>) -> Result<T, ComputeError> {
-        let res = self.compute_untyped(output.port.clone())?;
+        self.compute_with(output, &ComputationOptions::default())
+    }
+
+    /// Computes the result for a given output port with the given options.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned under the same conditions as [`ComputeGraph::compute`],
+    /// or if the computation was cancelled through [`ComputationOptions::cancel`].
+    pub fn compute_with<T: 'static>(
+        &self,
+        output: OutputPort<T>,
+        options: &ComputationOptions,
+    ) -> Result<T, ComputeError> {
+        let res = self.compute_untyped_with(output.port.clone(), options)?;
         let res = res
             .downcast::<T>()
             .map_err(|_| ComputeError::OutputTypeMismatch {
@@ -649,10 +1872,73 @@ impl ComputeGraph {
         Ok(*res)
     }
 
+    /// Computes the result for a given output port iteratively, feeding it back into an input.
+    ///
+    /// The graph is computed repeatedly. Starting with the second iteration, the value of
+    /// `feedback_input` is replaced by the result of the previous iteration, regardless of whether
+    /// it is connected. In the first iteration, the input is computed as usual, so the initial value
+    /// is provided by its connection or its default value.
+    ///
+    /// This allows iterative algorithms like constraint solvers, while the graph itself stays acyclic.
+    ///
+    /// # Arguments
+    ///
+    /// * `output` - The output port to compute.
+    /// * `feedback_input` - The input port receiving the result of the previous iteration.
+    /// * `max_iters` - The maximum number of iterations. At least one iteration is always computed,
+    ///   so `0` behaves like `1`.
+    /// * `options` - The options used for each iteration.
+    ///
+    /// # Returns
+    ///
+    /// The first result equal to the result of the previous iteration, or the last result if
+    /// `max_iters` iterations were computed without converging.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned under the same conditions as [`ComputeGraph::compute_with`].
+    pub fn compute_fixpoint<T: PartialEq + Clone + 'static>(
+        &self,
+        output: OutputPort<T>,
+        feedback_input: InputPort<T>,
+        max_iters: usize,
+        options: &ComputationOptions,
+    ) -> Result<T, ComputeError> {
+        let (output, feedback_input) = (output.port, feedback_input.port);
+        let downcast = |res: Box<dyn Any>| {
+            res.downcast::<T>()
+                .map(|res| *res)
+                .map_err(|_| ComputeError::OutputTypeMismatch {
+                    node: output.node.clone(),
+                })
+        };
+
+        let mut visited = HashSet::new();
+        let mut result =
+            downcast(self.compute_recursive(output.clone(), &mut visited, options, None)?)?;
+        for _ in 1..max_iters {
+            let previous = result.clone();
+            let feed_back = || Box::new(previous.clone()) as Box<dyn Any>;
+            let mut visited = HashSet::new();
+            result = downcast(self.compute_recursive(
+                output.clone(),
+                &mut visited,
+                options,
+                Some((&feedback_input, &feed_back)),
+            )?)?;
+            if result == previous {
+                break;
+            }
+        }
+        Ok(result)
+    }
+
     fn compute_recursive(
         &self,
         output: OutputPortUntyped,
         visited: &mut HashSet<NodeHandle>,
+        options: &ComputationOptions,
+        feedback: Feedback,
     ) -> Result<Box<dyn Any>, ComputeError> {
         // For now we use a simple, but more inefficient approach for computing the result:
         // Here we simply recursively compute the dependencies of the requested node in breadth first order.
@@ -673,11 +1959,7 @@ impl ComputeGraph {
             .nodes
             .iter()
             .find(|n| n.handle == output.node)
-            .ok_or_else(|| {
-                ComputeError::NodeNotFound(NodeHandle {
-                    node_name: output.node.node_name.clone(),
-                })
-            })?;
+            .ok_or_else(|| ComputeError::NodeNotFound(output.node.clone()))?;
         let output_handle = output_node.handle.clone();
 
         // Check for cycles, we use a simple set to detect if in the current path we already visited the node
@@ -686,6 +1968,15 @@ impl ComputeGraph {
         }
         visited.insert(output_handle.clone());
 
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "compute_node",
+            node = %output_handle.node_name,
+            output = output.output_name,
+            duration_us = tracing::field::Empty,
+        )
+        .entered();
+
         // Find the index of the output port
         let output_result_index = output_node
             .outputs
@@ -704,21 +1995,47 @@ impl ComputeGraph {
             let connection = self
                 .edges
                 .iter()
-                .find(|c| c.to.node == output_handle && c.to.input_name == input.0)
-                .ok_or_else(|| {
+                .find(|c| c.to.node == output_handle && c.to.input_name == input.0);
+
+            let is_feedback = |(port, _): &(&InputPortUntyped, _)| {
+                port.node == output_handle && port.input_name == input.0
+            };
+
+            // Compute the result of the input, or fall back to the default value of the node
+            let result = match (feedback.filter(is_feedback), connection) {
+                (Some((_, previous_result)), _) => previous_result(),
+                (None, Some(connection)) => {
+                    self.compute_recursive(connection.from.clone(), visited, options, feedback)?
+                }
+                (None, None) => output_node.node.default_input(input.0).ok_or_else(|| {
                     ComputeError::InputPortNotConnected(InputPortUntyped {
                         node: output_handle.clone(),
                         input_name: input.0,
                     })
-                })?;
-
-            // Compute the result of the input
-            let result = self.compute_recursive(connection.from.clone(), visited)?;
+                })?,
+            };
             dependency_results.push(result);
         }
 
+        if options.is_cancelled() {
+            return Err(ComputeError::Cancelled);
+        }
+
         // Run the node with the computed inputs
-        let output_result = output_node.node.run(&dependency_results);
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+        let output_result = output_node
+            .node
+            .run(&dependency_results)
+            .map_err(|source| ComputeError::NodeExecutionFailed {
+                node: output_handle.clone(),
+                source,
+            })?;
+        #[cfg(feature = "tracing")]
+        span.record(
+            "duration_us",
+            u64::try_from(start.elapsed().as_micros()).unwrap_or(u64::MAX),
+        );
         // check if the result has the correct type
         if output_result
             .iter()
@@ -731,6 +2048,19 @@ impl ComputeGraph {
                 node: output_handle.clone(),
             });
         }
+        for inspection in self
+            .inspections
+            .iter()
+            .filter(|i| i.port.node == output_handle)
+        {
+            if let Some(index) = output_node
+                .outputs
+                .iter()
+                .position(|o| o.0 == inspection.port.output_name)
+            {
+                (inspection.sink)(output_result[index].as_ref());
+            }
+        }
         let output = output_result
             .into_iter()
             .nth(output_result_index)
@@ -749,6 +2079,11 @@ impl ComputeGraph {
         self.nodes.iter()
     }
 
+    /// Returns an iterator over the connections in the graph.
+    pub fn iter_connections(&self) -> impl Iterator<Item = &Connection> {
+        self.edges.iter()
+    }
+
     /// Gets a node by its handle.
     ///
     /// This function searches for a node within the graph using the provided handle and returns a reference to the node if found.
@@ -780,6 +2115,37 @@ impl ComputeGraph {
     pub fn get_node_mut(&mut self, handle: &NodeHandle) -> Option<&mut GraphNode> {
         self.nodes.iter_mut().find(|node| &node.handle == handle)
     }
+
+    /// Attaches metadata of type `T` to a node, replacing any previous metadata of the same type.
+    ///
+    /// This is a shorthand for [`Metadata::insert`] on the [`GraphNode::metadata`] of the node.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MetadataError::NodeNotFound` if no node with the given handle exists.
+    pub fn set_metadata<T: 'static + Clone + fmt::Debug + Send + Sync>(
+        &mut self,
+        handle: &NodeHandle,
+        value: T,
+    ) -> Result<(), MetadataError> {
+        self.get_node_mut(handle)
+            .ok_or_else(|| MetadataError::NodeNotFound(handle.clone()))?
+            .metadata
+            .insert(value);
+        Ok(())
+    }
+
+    /// Gets the metadata of type `T` attached to a node.
+    ///
+    /// This is a shorthand for [`Metadata::get`] on the [`GraphNode::metadata`] of the node.
+    ///
+    /// # Returns
+    ///
+    /// The metadata, or `None` if the node does not exist or has no metadata of type `T`.
+    #[must_use]
+    pub fn get_metadata<T: 'static>(&self, handle: &NodeHandle) -> Option<&T> {
+        self.get_node(handle)?.metadata.get()
+    }
 }
 
 /// Represents an input port of a node, without carrying type information.
@@ -918,9 +2284,30 @@ impl<T> fmt::Display for OutputPort<T> {
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NodeHandle {
     pub node_name: String, // TODO: maybe associate with lifetime of the graph?
+    /// Set for handles of nodes that were never added to a graph, see [`GraphBuilder::add_node`].
+    detached: bool,
 }
 
 impl NodeHandle {
+    /// Creates a handle to the node with the given name.
+    ///
+    /// It is not guaranteed that the node exists.
+    #[must_use]
+    pub const fn new(node_name: String) -> Self {
+        Self {
+            node_name,
+            detached: false,
+        }
+    }
+
+    /// Returns `true` if this handle belongs to a node that was never added to a graph.
+    ///
+    /// See [`GraphBuilder::add_node`].
+    #[must_use]
+    pub const fn is_detached(&self) -> bool {
+        self.detached
+    }
+
     /// Create a [`InputPortUntyped`] from the node handle and the input name.
     ///
     /// This is useful when connecting nodes, when the concrete type of the node is not known at compile time.
@@ -976,17 +2363,57 @@ pub struct Connection {
     to: InputPortUntyped,
 }
 
+impl Connection {
+    /// Returns the output port this connection originates from.
+    #[must_use]
+    pub const fn from(&self) -> &OutputPortUntyped {
+        &self.from
+    }
+
+    /// Returns the input port this connection leads to.
+    #[must_use]
+    pub const fn to(&self) -> &InputPortUntyped {
+        &self.to
+    }
+}
+
 /// Represents a node in the graph.
 #[derive(Debug, Clone)]
 pub struct GraphNode {
     inputs: Vec<(&'static str, TypeId)>,
     outputs: Vec<(&'static str, TypeId)>,
+    input_type_names: Vec<&'static str>,
+    output_type_names: Vec<&'static str>,
     node: Box<dyn ExecutableNode>,
     handle: NodeHandle,
     pub metadata: Metadata,
 }
 
 impl GraphNode {
+    fn from_factory<N: NodeFactory + 'static>(node: N, name: String) -> Self {
+        Self {
+            inputs: N::inputs(),
+            outputs: N::outputs(),
+            input_type_names: N::input_type_names(),
+            output_type_names: N::output_type_names(),
+            node: Box::new(node),
+            handle: NodeHandle::new(name),
+            metadata: Metadata::default(),
+        }
+    }
+
+    fn from_dynamic(node: DynamicNode, name: String) -> Self {
+        Self {
+            inputs: node.inputs,
+            outputs: node.outputs,
+            input_type_names: node.input_type_names,
+            output_type_names: node.output_type_names,
+            node: node.executable,
+            handle: NodeHandle::new(name),
+            metadata: Metadata::default(),
+        }
+    }
+
     #[must_use]
     pub const fn handle(&self) -> &NodeHandle {
         &self.handle
@@ -1002,6 +2429,22 @@ impl GraphNode {
         &self.outputs
     }
 
+    /// Returns the names of the input types, in the same order as [`GraphNode::get_inputs`].
+    ///
+    /// See [`NodeFactory::input_type_names`].
+    #[must_use]
+    pub fn get_input_type_names(&self) -> &[&'static str] {
+        &self.input_type_names
+    }
+
+    /// Returns the names of the output types, in the same order as [`GraphNode::get_outputs`].
+    ///
+    /// See [`NodeFactory::output_type_names`].
+    #[must_use]
+    pub fn get_output_type_names(&self) -> &[&'static str] {
+        &self.output_type_names
+    }
+
     #[must_use]
     pub fn get_type_of_input(&self, input: &InputPortUntyped) -> Option<TypeId> {
         self.inputs
@@ -1026,7 +2469,7 @@ impl GraphNode {
 /// defining the logic that processes input data and produces output data.
 ///
 /// Implementors of this trait should always also implement the [`NodeFactory`] trait.
-pub trait ExecutableNode: std::fmt::Debug + DynClone + Send + Sync {
+pub trait ExecutableNode: Any + std::fmt::Debug + DynClone + Send + Sync {
     /// Executes the node's computation logic.
     ///
     /// This method takes boxed input data, processes it, and returns boxed output data.
@@ -1040,12 +2483,64 @@ pub trait ExecutableNode: std::fmt::Debug + DynClone + Send + Sync {
     /// # Returns
     ///
     /// A vector of boxed dynamic values representing the output data.
-    // TODO: add error handling
-    fn run(&self, input: &[Box<dyn Any>]) -> Vec<Box<dyn Any>>;
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NodeRunError`] if the computation failed or the input data did not have the expected types.
+    /// The error is reported by [`ComputeGraph::compute`] as [`ComputeError::NodeExecutionFailed`].
+    fn run(&self, input: &[Box<dyn Any>]) -> Result<Vec<Box<dyn Any>>, NodeRunError>;
+
+    /// Returns the value used for the input port `input` if it is not connected.
+    ///
+    /// Defaults to `None`, meaning that the input must be connected. With the [`node`] macro,
+    /// default values are declared with `#[default(...)]` on the input parameter.
+    fn default_input(&self, _input: &str) -> Option<Box<dyn Any>> {
+        None
+    }
+
+    /// Returns `true` if this node is equal to `other`, used by [`ComputeGraph::structurally_eq`].
+    ///
+    /// Defaults to `false`, since nodes can not be compared in general. The [`node`] macro
+    /// implements this with the `PartialEq` implementation of the node, if there is one.
+    fn partial_eq(&self, _other: &dyn ExecutableNode) -> bool {
+        false
+    }
 }
 
 dyn_clone::clone_trait_object!(ExecutableNode);
 
+/// Implementation details of the [`node`] macro, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use std::any::Any;
+
+    /// Selects how nodes are compared by [`crate::ExecutableNode::partial_eq`].
+    ///
+    /// Calling `(&NodeEq(node)).node_eq(other)` uses [`ViaPartialEq`] if the node implements
+    /// `PartialEq`, since it requires less autoref than [`ViaFallback`].
+    pub struct NodeEq<'a, T>(pub &'a T);
+
+    pub trait ViaPartialEq {
+        fn node_eq(&self, other: &dyn Any) -> bool;
+    }
+
+    impl<T: PartialEq + 'static> ViaPartialEq for NodeEq<'_, T> {
+        fn node_eq(&self, other: &dyn Any) -> bool {
+            other.downcast_ref::<T>() == Some(self.0)
+        }
+    }
+
+    pub trait ViaFallback {
+        fn node_eq(&self, other: &dyn Any) -> bool;
+    }
+
+    impl<T> ViaFallback for &NodeEq<'_, T> {
+        fn node_eq(&self, _other: &dyn Any) -> bool {
+            false
+        }
+    }
+}
+
 /// Trait for building a node.
 ///
 /// This trait defines the interface for creating nodes within a compute graph.
@@ -1079,6 +2574,23 @@ pub trait NodeFactory: ExecutableNode {
     /// - A `TypeId` representing the type of the output port.
     fn outputs() -> Vec<(&'static str, TypeId)>;
 
+    /// Returns human-readable names of the input types, in the same order as [`NodeFactory::inputs`].
+    ///
+    /// The names are created with [`std::any::type_name`] and are therefore only meant for
+    /// diagnostics, not for identifying a type. Defaults to [`UNKNOWN_TYPE_NAME`] for every port.
+    #[must_use]
+    fn input_type_names() -> Vec<&'static str> {
+        vec![UNKNOWN_TYPE_NAME; Self::inputs().len()]
+    }
+
+    /// Returns human-readable names of the output types, in the same order as [`NodeFactory::outputs`].
+    ///
+    /// See [`NodeFactory::input_type_names`].
+    #[must_use]
+    fn output_type_names() -> Vec<&'static str> {
+        vec![UNKNOWN_TYPE_NAME; Self::outputs().len()]
+    }
+
     /// Creates a handle for interacting with the node.
     ///
     /// This method takes a reference to a `GraphNode` and returns a handle
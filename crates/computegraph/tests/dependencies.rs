@@ -57,6 +57,42 @@ fn test_dynamic_graph() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_dynamic_closure_node() -> Result<()> {
+    let mut graph = ComputeGraph::new();
+    let value = graph.add_node(TestNodeConstant::new(7), "value".to_string())?;
+
+    // Port names only known at runtime
+    let input_name = format!("in_{}", 1);
+    let output_name = "doubled".to_string();
+    let node = DynamicNode::from_closure(
+        vec![(input_name, TypeId::of::<usize>())],
+        vec![(output_name, TypeId::of::<usize>())],
+        |input| {
+            let value = input[0]
+                .downcast_ref::<usize>()
+                .ok_or("input has the wrong type")?;
+            Ok(vec![Box::new(value * 2)])
+        },
+    );
+    let input_port = node.inputs()[0].0;
+    let output_port = node.outputs()[0].0;
+    assert_eq!(input_port, "in_1");
+    assert_eq!(output_port, "doubled");
+
+    let doubled = graph.add_node_dynamic(node, "doubled".to_string())?;
+    graph.connect_untyped(
+        value.output().into(),
+        doubled.clone().to_input_port(input_port),
+    )?;
+    assert_eq!(
+        graph.compute(doubled.to_output_port(output_port).to_typed::<usize>())?,
+        14
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_diamond_dependencies_and_cloning() -> Result<()> {
     // Here we will test a more complex graph with two diamond dependencies between nodes.
@@ -166,6 +202,42 @@ fn test_cycle_detection() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_reject_cycles_on_connect() -> Result<()> {
+    let mut graph = ComputeGraph::new();
+    assert!(!graph.rejects_cycles());
+    graph.set_reject_cycles(true);
+
+    let value = graph.add_node(TestNodeConstant::new(5), "value".to_string())?;
+    let node1 = graph.add_node(TestNodeAddition::new(), "node1".to_string())?;
+    let node2 = graph.add_node(TestNodeAddition::new(), "node2".to_string())?;
+    let node3 = graph.add_node(TestNodeAddition::new(), "node3".to_string())?;
+
+    graph.connect(node1.output(), node2.input_a())?;
+    graph.connect(node2.output(), node3.input_a())?;
+    match graph.connect(node3.output(), node1.input_a()) {
+        Err(ConnectError::WouldCreateCycle { from, to }) => {
+            assert_eq!(from.node, node3.handle);
+            assert_eq!(to.node, node1.handle);
+        }
+        _ => panic!("Expected ConnectError::WouldCreateCycle"),
+    }
+    // Connecting a node to itself is a cycle too
+    assert!(matches!(
+        graph.connect(node1.output(), node1.input_b()),
+        Err(ConnectError::WouldCreateCycle { .. })
+    ));
+
+    // The rejected connections were not added, so the graph stays computable
+    graph.connect(value.output(), node1.input_a())?;
+    graph.connect(value.output(), node1.input_b())?;
+    graph.connect(value.output(), node2.input_b())?;
+    graph.connect(value.output(), node3.input_b())?;
+    assert_eq!(graph.compute(node3.output())?, 20);
+
+    Ok(())
+}
+
 #[test]
 fn test_disconnected_subgraphs() -> Result<()> {
     let mut graph = ComputeGraph::new();
@@ -191,3 +263,145 @@ fn test_disconnected_subgraphs() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_validate() -> Result<()> {
+    let mut graph = ComputeGraph::new();
+    let value = graph.add_node(TestNodeConstant::new(5), "value".to_string())?;
+    let node1 = graph.add_node(TestNodeAddition::new(), "node1".to_string())?;
+    let node2 = graph.add_node(TestNodeAddition::new(), "node2".to_string())?;
+    let node3 = graph.add_node(TestNodeAddition::new(), "node3".to_string())?;
+    let node4 = graph.add_node(TestNodeAddition::new(), "node4".to_string())?;
+
+    graph.connect(value.output(), node1.input_b())?;
+    graph.connect(node1.output(), node2.input_a())?;
+    graph.connect(value.output(), node2.input_b())?;
+    assert_eq!(graph.validate(), Ok(()));
+
+    // Two independent cycles: node1 -> node2 -> node1 and node3 -> node3
+    let cycle_connection1 = graph.connect(node2.output(), node1.input_a())?;
+    let cycle_connection2 = graph.connect(node3.output(), node3.input_a())?;
+    // node4 is only downstream of a cycle and should not be reported
+    graph.connect(node1.output(), node4.input_a())?;
+
+    let errors = graph.validate().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    let mut cycle1 = vec![node1.handle.clone(), node2.handle.clone()];
+    cycle1.sort();
+    assert!(errors.contains(&GraphValidationError::CycleDetected(cycle1)));
+    assert!(
+        errors.contains(&GraphValidationError::CycleDetected(vec![node3
+            .handle
+            .clone()]))
+    );
+
+    graph.disconnect(&cycle_connection1)?;
+    graph.disconnect(&cycle_connection2)?;
+    assert!(graph.validate().is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn test_compute_cancelled() -> Result<()> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    /// Passes its input through, setting the flag once run.
+    #[derive(Debug, Clone)]
+    struct CancelAfterRun(Arc<AtomicBool>);
+
+    #[node(CancelAfterRun)]
+    fn run(&self, value: &usize) -> usize {
+        self.0.store(true, Ordering::Relaxed);
+        *value
+    }
+
+    let cancel = Arc::new(AtomicBool::new(false));
+    let mut graph = ComputeGraph::new();
+    let value = graph.add_node(TestNodeConstant::new(5), "value".to_string())?;
+    let cancel_node = graph.add_node(CancelAfterRun(cancel.clone()), "cancel".to_string())?;
+    let to_string = graph.add_node(TestNodeNumToString::new(), "to_string".to_string())?;
+    graph.connect(value.output(), cancel_node.input_value())?;
+    graph.connect(cancel_node.output(), to_string.input())?;
+
+    let options = ComputationOptions {
+        cancel: Some(cancel.clone()),
+    };
+    assert!(matches!(
+        graph.compute_with(to_string.output(), &options),
+        Err(ComputeError::Cancelled)
+    ));
+
+    cancel.store(false, Ordering::Relaxed);
+    assert_eq!(graph.compute_with(cancel_node.output(), &options)?, 5);
+    assert!(cancel.load(Ordering::Relaxed));
+    assert_eq!(graph.compute(to_string.output())?, "5");
+
+    Ok(())
+}
+
+#[test]
+fn test_compute_fixpoint() -> Result<()> {
+    /// One step of Heron's method for the square root of `target`.
+    #[derive(Debug, Clone)]
+    struct HeronStep {
+        target: u64,
+    }
+
+    #[node(HeronStep)]
+    fn run(&self, guess: &u64) -> u64 {
+        (guess + self.target / guess) / 2
+    }
+
+    let mut graph = ComputeGraph::new();
+    let initial = graph.add_node(TestNodeConstant::new(1000), "initial".to_string())?;
+    let to_u64 = graph.add_node(TestNodeToU64, "to_u64".to_string())?;
+    let step = graph.add_node(HeronStep { target: 1_000_000 }, "step".to_string())?;
+    graph.connect(initial.output(), to_u64.input_value())?;
+    graph.connect(to_u64.output(), step.input_guess())?;
+
+    // Without feedback, only a single step is computed
+    assert_eq!(graph.compute(step.output())?, 1000);
+
+    let options = ComputationOptions::default();
+    let root = graph.compute_fixpoint(step.output(), step.input_guess(), 100, &options)?;
+    assert_eq!(root, 1000);
+
+    // Start far away from the solution
+    let mut graph = ComputeGraph::new();
+    let initial = graph.add_node(TestNodeConstant::new(1_000_000), "initial".to_string())?;
+    let to_u64 = graph.add_node(TestNodeToU64, "to_u64".to_string())?;
+    let step = graph.add_node(HeronStep { target: 1_000_000 }, "step".to_string())?;
+    graph.connect(initial.output(), to_u64.input_value())?;
+    graph.connect(to_u64.output(), step.input_guess())?;
+
+    assert_eq!(
+        graph.compute_fixpoint(step.output(), step.input_guess(), 100, &options)?,
+        1000
+    );
+    // Stops after the iteration limit
+    assert_eq!(
+        graph.compute_fixpoint(step.output(), step.input_guess(), 2, &options)?,
+        250_001
+    );
+    // Without iterations, the graph is computed once, just like `compute`
+    assert_eq!(
+        graph.compute_fixpoint(step.output(), step.input_guess(), 0, &options)?,
+        graph.compute(step.output())?
+    );
+    assert_eq!(
+        graph.compute_fixpoint(step.output(), step.input_guess(), 1, &options)?,
+        500_000
+    );
+
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+struct TestNodeToU64;
+
+#[node(TestNodeToU64)]
+fn run(&self, value: &usize) -> u64 {
+    *value as u64
+}
@@ -3,6 +3,7 @@ mod common;
 use anyhow::Result;
 use common::*;
 use computegraph::*;
+use std::any::TypeId;
 
 #[test]
 fn test_connect_already_connected() -> Result<()> {
@@ -39,3 +40,287 @@ fn test_duplicate_node_names() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_iter_connections() -> Result<()> {
+    let mut graph = ComputeGraph::new();
+
+    let value1 = graph.add_node(TestNodeConstant::new(5), "value1".to_string())?;
+    let value2 = graph.add_node(TestNodeConstant::new(7), "value2".to_string())?;
+    let addition = graph.add_node(TestNodeAddition::new(), "addition".to_string())?;
+
+    assert_eq!(graph.iter_connections().count(), 0);
+
+    graph.connect(value1.output(), addition.input_a())?;
+    graph.connect(value2.output(), addition.input_b())?;
+
+    let mut connections: Vec<_> = graph
+        .iter_connections()
+        .map(|c| (c.from().clone(), c.to().clone()))
+        .collect();
+    connections.sort();
+    assert_eq!(
+        connections,
+        vec![
+            (value1.output().into(), addition.input_a().into()),
+            (value2.output().into(), addition.input_b().into()),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_connect_with_adapter() -> Result<()> {
+    #[derive(Debug, Clone)]
+    struct Negate {}
+    #[node(Negate)]
+    fn run(&self, value: &i64) -> i64 {
+        -value
+    }
+
+    let mut graph = ComputeGraph::new();
+
+    let value = graph.add_node(TestNodeConstant::new(5), "value".to_string())?;
+    let negate = graph.add_node(Negate {}, "negate".to_string())?;
+
+    let adapter = graph.connect_with(value.output(), negate.input_value(), |v: &usize| {
+        i64::try_from(*v).unwrap()
+    })?;
+    assert_eq!(graph.iter_nodes().count(), 3);
+    assert_eq!(graph.iter_connections().count(), 2);
+    assert_eq!(graph.compute(negate.output())?, -5);
+
+    // The target input is already connected, the graph stays unchanged
+    let res = graph.connect_with(value.output(), negate.input_value(), |v: &usize| {
+        i64::try_from(*v).unwrap()
+    });
+    assert!(matches!(
+        res,
+        Err(ConnectError::InputPortAlreadyConnected { .. })
+    ));
+    assert_eq!(graph.iter_nodes().count(), 3);
+    assert_eq!(graph.iter_connections().count(), 2);
+
+    graph.remove_node(adapter)?;
+    assert_eq!(graph.iter_connections().count(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_graph_builder() -> Result<()> {
+    let mut builder = GraphBuilder::new();
+    let value1 = builder.add_node(TestNodeConstant::new(5), "value1".to_string());
+    let value2 = builder.add_node(TestNodeConstant::new(7), "value2".to_string());
+    let addition = builder.add_node(TestNodeAddition::new(), "addition".to_string());
+    builder
+        .connect(value1.output(), addition.input_a())
+        .connect(value2.output(), addition.input_b());
+
+    let graph = builder.build().expect("graph should be valid");
+    assert_eq!(graph.iter_nodes().count(), 3);
+    assert_eq!(graph.iter_connections().count(), 2);
+    assert_eq!(graph.compute(addition.output())?, 12);
+
+    Ok(())
+}
+
+#[test]
+fn test_graph_builder_collects_errors() {
+    let mut builder = GraphBuilder::new();
+    let value = builder.add_node(TestNodeConstant::new(5), "value".to_string());
+    builder.add_node(TestNodeConstant::new(7), "value".to_string());
+    let to_string = builder.add_node(TestNodeNumToString::new(), "to_string".to_string());
+    builder.add_node(TestNodeNumToString::new(), "to_string".to_string());
+    let addition = builder.add_node(TestNodeAddition::new(), "addition".to_string());
+    builder
+        .connect(value.output(), addition.input_a())
+        .connect_untyped(to_string.output().into(), addition.input_b().into());
+
+    let errors = builder.build().expect_err("graph should be invalid");
+    assert_eq!(errors.len(), 3);
+    assert!(
+        matches!(&errors[0], BuildError::Add(AddError::DuplicateName(name)) if name == "value")
+    );
+    assert!(
+        matches!(&errors[1], BuildError::Add(AddError::DuplicateName(name)) if name == "to_string")
+    );
+    assert!(matches!(
+        &errors[2],
+        BuildError::Connect(ConnectError::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_graph_builder_duplicate_handle() {
+    let mut builder = GraphBuilder::new();
+    let first = builder.add_node(TestNodeConstant::new(5), "value".to_string());
+    let duplicate = builder.add_node(TestNodeConstant::new(7), "value".to_string());
+    // The duplicate keeps its name, but is distinguishable from the first node
+    assert_eq!(duplicate.handle.node_name, "value");
+    assert!(!first.handle.is_detached());
+    assert!(duplicate.handle.is_detached());
+    assert_ne!(first.handle, duplicate.handle);
+    let addition = builder.add_node(TestNodeAddition::new(), "addition".to_string());
+    builder
+        .connect(first.output(), addition.input_a())
+        .connect(duplicate.output(), addition.input_b());
+
+    // The connection through the duplicate is rejected instead of using the first node
+    let errors = builder.build().expect_err("graph should be invalid");
+    assert_eq!(errors.len(), 2);
+    assert!(
+        matches!(&errors[0], BuildError::Add(AddError::DuplicateName(name)) if name == "value")
+    );
+    assert!(matches!(
+        &errors[1],
+        BuildError::Connect(ConnectError::NodeNotFound(node)) if *node == duplicate.handle
+    ));
+
+    // Neither can it be used with a graph that has a node of the same name
+    let mut graph = ComputeGraph::new();
+    let value = graph
+        .add_node(TestNodeConstant::new(5), "value".to_string())
+        .unwrap();
+    let to_string = graph
+        .add_node(TestNodeNumToString::new(), "to_string".to_string())
+        .unwrap();
+    assert!(graph.get_node(&value.handle).is_some());
+    assert!(graph.get_node(&duplicate.handle).is_none());
+    assert!(matches!(
+        graph.connect(duplicate.output(), to_string.input()),
+        Err(ConnectError::NodeNotFound(_))
+    ));
+    assert!(matches!(
+        graph.compute(duplicate.output()),
+        Err(ComputeError::NodeNotFound(_))
+    ));
+
+    let mut builder = GraphBuilder::new();
+    builder.add_node_dynamic(TestNodeConstant::new(5).into(), "value".to_string());
+    let duplicate = builder.add_node_dynamic(TestNodeConstant::new(7).into(), "value".to_string());
+    let addition = builder.add_node(TestNodeAddition::new(), "addition".to_string());
+    builder.connect_untyped(
+        duplicate.clone().to_output_port("output"),
+        addition.input_a().into(),
+    );
+    let errors = builder.build().expect_err("graph should be invalid");
+    assert!(matches!(
+        &errors[1],
+        BuildError::Connect(ConnectError::NodeNotFound(node)) if *node == duplicate
+    ));
+}
+
+#[test]
+fn test_structurally_eq() -> Result<()> {
+    let mut graph1 = ComputeGraph::new();
+    let value1 = graph1.add_node(TestNodeConstant::new(5), "value1".to_string())?;
+    let value2 = graph1.add_node(TestNodeConstant::new(7), "value2".to_string())?;
+    let addition = graph1.add_node(TestNodeAddition::new(), "addition".to_string())?;
+    graph1.connect(value1.output(), addition.input_a())?;
+    graph1.connect(value2.output(), addition.input_b())?;
+
+    // Same graph, built in a different order
+    let mut graph2 = ComputeGraph::new();
+    let addition = graph2.add_node(TestNodeAddition::new(), "addition".to_string())?;
+    let value2 = graph2.add_node(TestNodeConstant::new(7), "value2".to_string())?;
+    graph2.connect(value2.output(), addition.input_b())?;
+    let value1 = graph2.add_node(TestNodeConstant::new(5), "value1".to_string())?;
+    graph2.connect(value1.output(), addition.input_a())?;
+
+    assert!(graph1.structurally_eq(&graph2));
+    assert_eq!(graph1, graph2);
+
+    // Changing the state of a node
+    let mut graph3 = graph2.clone();
+    graph3.remove_node(value1.handle.clone())?;
+    let value1 = graph3.add_node(TestNodeConstant::new(6), "value1".to_string())?;
+    graph3.connect(value1.output(), addition.input_a())?;
+    assert!(!graph1.structurally_eq(&graph3));
+
+    // Swapping connections
+    let mut graph4 = graph2.clone();
+    for connection in graph4.iter_connections().cloned().collect::<Vec<_>>() {
+        graph4.disconnect(&connection)?;
+    }
+    graph4.connect(value1.output(), addition.input_b())?;
+    graph4.connect(value2.output(), addition.input_a())?;
+    assert_ne!(graph1, graph4);
+
+    Ok(())
+}
+
+#[test]
+fn test_to_dot() -> Result<()> {
+    let mut graph = ComputeGraph::new();
+    let value1 = graph.add_node(TestNodeConstant::new(5), "value1".to_string())?;
+    let value2 = graph.add_node(TestNodeConstant::new(7), "value2".to_string())?;
+    let addition = graph.add_node(TestNodeAddition::new(), "addition".to_string())?;
+    graph.connect(value1.output(), addition.input_a())?;
+    graph.connect(value2.output(), addition.input_b())?;
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph {"));
+    assert!(dot.ends_with('}'));
+    assert!(
+        dot.contains(r#""value1" [shape=box, label="value1\ninputs: \noutputs: output: usize"];"#)
+    );
+    assert!(
+        dot.contains(r#""addition" [shape=box, label="addition\ninputs: a: usize, b: usize\noutputs: output: usize"];"#)
+    );
+    assert!(dot.contains(r#""value1" -> "addition" [label="output -> a"];"#));
+    assert!(dot.contains(r#""value2" -> "addition" [label="output -> b"];"#));
+
+    Ok(())
+}
+
+#[test]
+fn test_structurally_eq_closures() -> Result<()> {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Negate {}
+    #[node(Negate)]
+    fn run(&self, value: &i64) -> i64 {
+        -value
+    }
+
+    let build_adapted = |offset: i64| -> Result<ComputeGraph> {
+        let mut graph = ComputeGraph::new();
+        let value = graph.add_node(TestNodeConstant::new(5), "value".to_string())?;
+        let negate = graph.add_node(Negate {}, "negate".to_string())?;
+        graph.connect_with(value.output(), negate.input_value(), move |v: &usize| {
+            i64::try_from(*v).unwrap() + offset
+        })?;
+        Ok(graph)
+    };
+    let adapted = build_adapted(0)?;
+    assert_eq!(adapted, adapted.clone());
+    assert_ne!(adapted, build_adapted(1)?);
+
+    let build_closure = |factor: usize| -> Result<ComputeGraph> {
+        let mut graph = ComputeGraph::new();
+        let node = DynamicNode::from_closure(
+            vec![],
+            vec![("output".to_string(), TypeId::of::<usize>())],
+            move |_| Ok(vec![Box::new(factor)]),
+        );
+        graph.add_node_dynamic(node, "closure".to_string())?;
+        Ok(graph)
+    };
+    let closure = build_closure(2)?;
+    assert_eq!(closure, closure.clone());
+    assert_ne!(closure, build_closure(3)?);
+
+    // Nodes without a `PartialEq` implementation are never equal
+    #[derive(Debug, Clone)]
+    struct Incomparable {}
+    #[node(Incomparable)]
+    fn run(&self) -> usize {
+        0
+    }
+    let mut graph = ComputeGraph::new();
+    graph.add_node(Incomparable {}, "node".to_string())?;
+    assert_ne!(graph, graph.clone());
+
+    Ok(())
+}
@@ -74,3 +74,175 @@ fn test_node_removal() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_merge_graphs() -> Result<()> {
+    let mut inner = ComputeGraph::new();
+    let inner_value = inner.add_node(TestNodeConstant::new(5), "value".to_string())?;
+    let inner_addition = inner.add_node(TestNodeAddition::new(), "addition".to_string())?;
+    inner.connect(inner_value.output(), inner_addition.input_a())?;
+
+    let mut graph = ComputeGraph::new();
+    let value = graph.add_node(TestNodeConstant::new(3), "value".to_string())?;
+    let to_string = graph.add_node(TestNodeNumToString::new(), "to_string".to_string())?;
+
+    let handles = graph.merge(inner.clone(), "inner/")?;
+    assert_eq!(handles.len(), 2);
+    let addition = TestNodeAdditionHandle {
+        handle: handles[&inner_addition.handle].clone(),
+    };
+    assert_eq!(addition.handle.node_name, "inner/addition");
+
+    // Connect across the boundary of the merged graph
+    graph.connect(value.output(), addition.input_b())?;
+    graph.connect(addition.output(), to_string.input())?;
+    assert_eq!(graph.compute(to_string.output())?, "8".to_string());
+
+    // Merging again with the same prefix would create duplicate names
+    assert!(matches!(
+        graph.merge(inner, "inner/"),
+        Err(AddError::DuplicateName(_))
+    ));
+    assert_eq!(graph.iter_nodes().count(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_subgraph() -> Result<()> {
+    let mut graph = ComputeGraph::new();
+    let value1 = graph.add_node(TestNodeConstant::new(2), "value1".to_string())?;
+    let value2 = graph.add_node(TestNodeConstant::new(3), "value2".to_string())?;
+    let unrelated = graph.add_node(TestNodeConstant::new(4), "unrelated".to_string())?;
+    let addition = graph.add_node(TestNodeAddition::new(), "addition".to_string())?;
+    let to_string = graph.add_node(TestNodeNumToString::new(), "to_string".to_string())?;
+    let other_string = graph.add_node(TestNodeNumToString::new(), "other_string".to_string())?;
+
+    graph.connect(value1.output(), addition.input_a())?;
+    graph.connect(value2.output(), addition.input_b())?;
+    graph.connect(addition.output(), to_string.input())?;
+    graph.connect(unrelated.output(), other_string.input())?;
+
+    let subgraph = graph.subgraph(&addition.output().into())?;
+    let mut names: Vec<_> = subgraph
+        .iter_nodes()
+        .map(|n| n.handle().node_name.clone())
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["addition", "value1", "value2"]);
+    assert_eq!(subgraph.compute(addition.output())?, 5);
+
+    assert!(matches!(
+        graph.subgraph(&NodeHandle::new("missing".to_string()).to_output_port("output")),
+        Err(ComputeError::NodeNotFound(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_inspect_output() -> Result<()> {
+    let mut graph = ComputeGraph::new();
+    let value1 = graph.add_node(TestNodeConstant::new(2), "value1".to_string())?;
+    let value2 = graph.add_node(TestNodeConstant::new(3), "value2".to_string())?;
+    let addition = graph.add_node(TestNodeAddition::new(), "addition".to_string())?;
+    let to_string = graph.add_node(TestNodeNumToString::new(), "to_string".to_string())?;
+    graph.connect(value1.output(), addition.input_a())?;
+    graph.connect(value2.output(), addition.input_b())?;
+    graph.connect(addition.output(), to_string.input())?;
+
+    let sink = std::sync::Arc::new(std::sync::Mutex::new(None));
+    graph.inspect(addition.output(), sink.clone())?;
+    assert_eq!(*sink.lock().unwrap(), None);
+
+    // The inspected value is copied out, without changing the result
+    assert_eq!(graph.compute(to_string.output())?, "5".to_string());
+    assert_eq!(*sink.lock().unwrap(), Some(5));
+
+    // Computing a part of the graph that does not include the node leaves the sink untouched
+    *sink.lock().unwrap() = None;
+    graph.compute(value1.output())?;
+    assert_eq!(*sink.lock().unwrap(), None);
+
+    graph.remove_node(addition.clone())?;
+    graph.add_node(TestNodeAddition::new(), "addition".to_string())?;
+    graph.connect(value1.output(), addition.input_a())?;
+    graph.connect(value1.output(), addition.input_b())?;
+    graph.connect(addition.output(), to_string.input())?;
+    assert_eq!(graph.compute(to_string.output())?, "4".to_string());
+    assert_eq!(
+        *sink.lock().unwrap(),
+        None,
+        "Inspections should be removed together with their node"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_inspect_dangling_output() -> Result<()> {
+    let mut inner = ComputeGraph::new();
+    let value = inner.add_node(TestNodeConstant::new(2), "value".to_string())?;
+    let sink = std::sync::Arc::new(std::sync::Mutex::new(None));
+    inner.inspect(value.output(), sink.clone())?;
+
+    // A port on a node that is not part of the graph can not be inspected
+    let missing = TestNodeConstantHandle {
+        handle: NodeHandle::new("missing".to_string()),
+    };
+    assert!(matches!(
+        inner.inspect(missing.output(), sink.clone()),
+        Err(InspectError::NodeNotFound(node)) if node == missing.handle
+    ));
+    // Neither can a node that was removed before the inspection was added
+    let removed = inner.add_node(TestNodeConstant::new(3), "removed".to_string())?;
+    inner.remove_node(removed.clone())?;
+    assert!(matches!(
+        inner.inspect(removed.output(), sink.clone()),
+        Err(InspectError::NodeNotFound(_))
+    ));
+
+    let mut graph = ComputeGraph::new();
+    let handles = graph.merge(inner, "inner/")?;
+    let value = TestNodeConstantHandle {
+        handle: handles[&value.handle].clone(),
+    };
+    assert_eq!(graph.compute(value.output())?, 2);
+    assert_eq!(*sink.lock().unwrap(), Some(2));
+
+    Ok(())
+}
+
+#[test]
+fn test_rename_node() -> Result<()> {
+    let mut graph = ComputeGraph::new();
+    let value = graph.add_node(TestNodeConstant::new(5), "value".to_string())?;
+    let one = graph.add_node(TestNodeConstant::new(1), "one".to_string())?;
+    let addition = graph.add_node(TestNodeAddition::new(), "addition".to_string())?;
+    let to_string = graph.add_node(TestNodeNumToString::new(), "to_string".to_string())?;
+    graph.connect(value.output(), addition.input_a())?;
+    graph.connect(one.output(), addition.input_b())?;
+    graph.connect(addition.output(), to_string.input())?;
+
+    let renamed = TestNodeAdditionHandle {
+        handle: graph.rename_node(&addition.handle, "sum".to_string())?,
+    };
+    assert_eq!(renamed.handle.node_name, "sum");
+    assert!(graph.get_node(&addition.handle).is_none());
+
+    // Both incoming and outgoing connections are kept
+    assert_eq!(graph.compute(to_string.output())?, "6".to_string());
+    assert_eq!(graph.compute(renamed.output())?, 6);
+    assert!(graph.compute(addition.output()).is_err());
+
+    assert!(matches!(
+        graph.rename_node(&renamed.handle, "value".to_string()),
+        Err(RenameNodeError::DuplicateName(_))
+    ));
+    assert!(matches!(
+        graph.rename_node(&addition.handle, "other".to_string()),
+        Err(RenameNodeError::NodeNotFound(_))
+    ));
+
+    Ok(())
+}
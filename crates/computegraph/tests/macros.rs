@@ -1,5 +1,8 @@
-use computegraph::{node, ExecutableNode, NodeFactory};
-use std::any::TypeId;
+use computegraph::{
+    node, ComputeError, ComputeGraph, ExecutableNode, GraphNode, NodeFactory, NodeHandle,
+    NodeRunError, UNKNOWN_TYPE_NAME,
+};
+use std::any::{type_name, Any, TypeId};
 
 #[test]
 fn test_macro_node() {
@@ -44,11 +47,9 @@ fn test_macro_node() {
         text.repeat(*repeat_count)
     }
 
-    // TODO: generics support
-
     assert_eq!(<Node1 as NodeFactory>::inputs(), vec![]);
     assert_eq!(<Node1 as NodeFactory>::outputs(), vec![]);
-    let res = ExecutableNode::run(&Node1 {}, &[]);
+    let res = ExecutableNode::run(&Node1 {}, &[]).unwrap();
     assert_eq!(res.len(), 0);
 
     assert_eq!(<Node2 as NodeFactory>::inputs(), vec![]);
@@ -71,7 +72,7 @@ fn test_macro_node() {
             ("world", TypeId::of::<String>())
         ]
     );
-    let res = ExecutableNode::run(&Node4 {}, &[]);
+    let res = ExecutableNode::run(&Node4 {}, &[]).unwrap();
     assert_eq!(res.len(), 2);
     assert_eq!(res[0].downcast_ref::<String>().unwrap(), "hello");
     assert_eq!(res[1].downcast_ref::<String>().unwrap(), "world");
@@ -84,7 +85,8 @@ fn test_macro_node() {
         <Node5 as NodeFactory>::outputs(),
         vec![("output", TypeId::of::<usize>())]
     );
-    let res = ExecutableNode::run(&Node6 {}, &[Box::new("hi".to_string()), Box::new(3_usize)]);
+    let res =
+        ExecutableNode::run(&Node6 {}, &[Box::new("hi".to_string()), Box::new(3_usize)]).unwrap();
     assert_eq!(res.len(), 1);
     assert_eq!(res[0].downcast_ref::<String>().unwrap(), "hihihi");
 
@@ -99,7 +101,342 @@ fn test_macro_node() {
         <Node6 as NodeFactory>::outputs(),
         vec![("output", TypeId::of::<String>())]
     );
-    let res = ExecutableNode::run(&Node6 {}, &[Box::new("hi".to_string()), Box::new(3_usize)]);
+    let res =
+        ExecutableNode::run(&Node6 {}, &[Box::new("hi".to_string()), Box::new(3_usize)]).unwrap();
     assert_eq!(res.len(), 1);
     assert_eq!(res[0].downcast_ref::<String>().unwrap(), "hihihi");
 }
+
+#[test]
+fn test_macro_fallible_node() {
+    #[derive(Debug, Clone)]
+    struct CheckedDivision {}
+    #[node(CheckedDivision -> (quotient, remainder), fallible)]
+    fn run(&self, a: &usize, b: &usize) -> Result<(usize, usize), String> {
+        if *b == 0 {
+            Err("division by zero".to_string())
+        } else {
+            Ok((a / b, a % b))
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Constant {
+        value: usize,
+    }
+    #[node(Constant)]
+    fn run(&self) -> usize {
+        self.value
+    }
+
+    assert_eq!(
+        <CheckedDivision as NodeFactory>::outputs(),
+        vec![
+            ("quotient", TypeId::of::<usize>()),
+            ("remainder", TypeId::of::<usize>())
+        ]
+    );
+    let res =
+        ExecutableNode::run(&CheckedDivision {}, &[Box::new(7_usize), Box::new(2_usize)]).unwrap();
+    assert_eq!(res[0].downcast_ref::<usize>().unwrap(), &3);
+    assert_eq!(res[1].downcast_ref::<usize>().unwrap(), &1);
+    // Inputs of the wrong type are reported as an error instead of panicking
+    assert!(ExecutableNode::run(&CheckedDivision {}, &[Box::new(7_usize)]).is_err());
+    assert!(
+        ExecutableNode::run(&CheckedDivision {}, &[Box::new(7_usize), Box::new(2_i32)]).is_err()
+    );
+
+    let mut graph = ComputeGraph::new();
+    let a = graph
+        .add_node(Constant { value: 7 }, "a".to_string())
+        .unwrap();
+    let b = graph
+        .add_node(Constant { value: 0 }, "b".to_string())
+        .unwrap();
+    let division = graph
+        .add_node(CheckedDivision {}, "division".to_string())
+        .unwrap();
+    graph.connect(a.output(), division.input_a()).unwrap();
+    graph.connect(b.output(), division.input_b()).unwrap();
+
+    match graph.compute(division.output_quotient()) {
+        Err(ComputeError::NodeExecutionFailed { node, source }) => {
+            assert_eq!(node, division.handle);
+            assert_eq!(source.to_string(), "division by zero");
+        }
+        _ => panic!("Expected ComputeError::NodeExecutionFailed"),
+    }
+}
+
+#[test]
+fn test_macro_result_output_node() {
+    #[derive(Debug, Clone)]
+    struct Parse {}
+    #[node(Parse)]
+    fn run(&self, text: &String) -> Result<usize, String> {
+        text.parse().map_err(|_| format!("{text} is not a number"))
+    }
+
+    #[derive(Debug, Clone)]
+    struct Text {
+        text: String,
+    }
+    #[node(Text)]
+    fn run(&self) -> String {
+        self.text.clone()
+    }
+
+    assert_eq!(
+        <Parse as NodeFactory>::outputs(),
+        vec![("output", TypeId::of::<Result<usize, String>>())]
+    );
+
+    let mut graph = ComputeGraph::new();
+    let number = graph
+        .add_node(
+            Text {
+                text: "42".to_string(),
+            },
+            "number".to_string(),
+        )
+        .unwrap();
+    let word = graph
+        .add_node(
+            Text {
+                text: "abc".to_string(),
+            },
+            "word".to_string(),
+        )
+        .unwrap();
+    let parse_number = graph
+        .add_node(Parse {}, "parse_number".to_string())
+        .unwrap();
+    let parse_word = graph.add_node(Parse {}, "parse_word".to_string()).unwrap();
+    graph
+        .connect(number.output(), parse_number.input_text())
+        .unwrap();
+    graph
+        .connect(word.output(), parse_word.input_text())
+        .unwrap();
+
+    assert_eq!(graph.compute(parse_number.output()).unwrap(), Ok(42));
+    // An `Err` is passed on as data instead of failing the computation
+    assert_eq!(
+        graph.compute(parse_word.output()).unwrap(),
+        Err("abc is not a number".to_string())
+    );
+}
+
+#[test]
+fn test_macro_generic_node() {
+    #[derive(Debug, Clone)]
+    struct Constant<T> {
+        value: T,
+    }
+    #[node(Constant<T: Clone + std::fmt::Debug + Send + Sync + 'static>)]
+    fn run(&self) -> T {
+        self.value.clone()
+    }
+
+    #[derive(Debug, Clone)]
+    struct PassThrough<T> {
+        _marker: std::marker::PhantomData<T>,
+    }
+    impl<T> PassThrough<T> {
+        const fn new() -> Self {
+            Self {
+                _marker: std::marker::PhantomData,
+            }
+        }
+    }
+    #[node(PassThrough<T: Clone + std::fmt::Debug + Send + Sync + 'static> -> value)]
+    fn run(&self, value: &T) -> T {
+        value.clone()
+    }
+
+    assert_eq!(
+        <PassThrough<i32> as NodeFactory>::inputs(),
+        vec![("value", TypeId::of::<i32>())]
+    );
+    assert_eq!(
+        <PassThrough<String> as NodeFactory>::outputs(),
+        vec![("value", TypeId::of::<String>())]
+    );
+
+    let mut graph = ComputeGraph::new();
+    let number = graph
+        .add_node(Constant { value: 21_i32 }, "number".to_string())
+        .unwrap();
+    let text = graph
+        .add_node(
+            Constant {
+                value: "hello".to_string(),
+            },
+            "text".to_string(),
+        )
+        .unwrap();
+    let pass_number: PassThroughHandle<i32> = graph
+        .add_node(PassThrough::new(), "pass_number".to_string())
+        .unwrap();
+    let pass_text: PassThroughHandle<String> = graph
+        .add_node(PassThrough::new(), "pass_text".to_string())
+        .unwrap();
+    graph
+        .connect(number.output(), pass_number.input_value())
+        .unwrap();
+    graph
+        .connect(text.output(), pass_text.input_value())
+        .unwrap();
+
+    assert_eq!(graph.compute(pass_number.output_value()).unwrap(), 21);
+    assert_eq!(graph.compute(pass_text.output_value()).unwrap(), "hello");
+    assert_eq!(pass_number.clone(), pass_number);
+}
+
+#[test]
+fn test_macro_const_generic_node() {
+    #[derive(Debug, Clone)]
+    struct Repeat<const N: usize> {}
+    #[node(Repeat<const N: usize>)]
+    fn run(&self, value: &i32) -> [i32; N] {
+        [*value; N]
+    }
+
+    #[derive(Debug, Clone)]
+    struct Constant {}
+    #[node(Constant)]
+    fn run(&self) -> i32 {
+        4
+    }
+
+    #[derive(Debug, Clone)]
+    struct Fill<T, const N: usize> {
+        value: T,
+    }
+    #[node(Fill<T: Clone + std::fmt::Debug + Send + Sync + 'static, const N: usize> -> values)]
+    fn run(&self) -> Vec<T> {
+        vec![self.value.clone(); N]
+    }
+
+    assert_eq!(
+        <Repeat<3> as NodeFactory>::outputs(),
+        vec![("output", TypeId::of::<[i32; 3]>())]
+    );
+
+    let mut graph = ComputeGraph::new();
+    let fill: FillHandle<i32, 2> = graph
+        .add_node(Fill { value: 7 }, "fill".to_string())
+        .unwrap();
+    let constant = graph.add_node(Constant {}, "constant".to_string()).unwrap();
+    let repeat: RepeatHandle<3> = graph.add_node(Repeat {}, "repeat".to_string()).unwrap();
+    graph
+        .connect(constant.output(), repeat.input_value())
+        .unwrap();
+    assert_eq!(graph.compute(fill.output_values()).unwrap(), vec![7, 7]);
+    assert_eq!(graph.compute(repeat.output()).unwrap(), [4, 4, 4]);
+    assert_eq!(repeat.clone(), repeat);
+}
+
+#[test]
+fn test_macro_default_input() {
+    #[derive(Debug, Clone)]
+    struct Constant {
+        value: i32,
+    }
+    #[node(Constant)]
+    fn run(&self) -> i32 {
+        self.value
+    }
+
+    #[derive(Debug, Clone)]
+    struct Double {}
+    #[node(Double)]
+    fn run(&self, #[default(5)] count: &i32) -> i32 {
+        count * 2
+    }
+
+    let mut graph = ComputeGraph::new();
+    let double = graph.add_node(Double {}, "double".to_string()).unwrap();
+
+    // The unconnected input uses the default value
+    assert_eq!(graph.compute(double.output()).unwrap(), 10);
+
+    // A connected input uses the connection
+    let constant = graph
+        .add_node(Constant { value: 7 }, "constant".to_string())
+        .unwrap();
+    graph
+        .connect(constant.output(), double.input_count())
+        .unwrap();
+    assert_eq!(graph.compute(double.output()).unwrap(), 14);
+}
+
+#[test]
+fn test_macro_type_names() {
+    #[derive(Debug, Clone)]
+    struct Node {}
+    #[node(Node -> (length, text))]
+    fn run(&self, value: &i32, label: &String) -> (i32, String) {
+        (*value, label.clone())
+    }
+
+    assert_eq!(
+        Node::input_type_names(),
+        vec![type_name::<i32>(), type_name::<String>()]
+    );
+    assert_eq!(
+        Node::output_type_names(),
+        vec![type_name::<i32>(), type_name::<String>()]
+    );
+
+    let mut graph = ComputeGraph::new();
+    let node = graph.add_node(Node {}, "node".to_string()).unwrap();
+    let gnode = graph.get_node(&node.handle).unwrap();
+    assert_eq!(
+        gnode.get_input_type_names(),
+        [type_name::<i32>(), type_name::<String>()]
+    );
+    assert_eq!(
+        gnode.get_output_type_names(),
+        [type_name::<i32>(), type_name::<String>()]
+    );
+}
+
+#[test]
+fn test_default_type_names() {
+    // Hand-written nodes do not have to provide type names
+    #[derive(Debug, Clone)]
+    struct Node {}
+    impl NodeFactory for Node {
+        type Handle = NodeHandle;
+
+        fn inputs() -> Vec<(&'static str, TypeId)> {
+            vec![("value", TypeId::of::<i32>())]
+        }
+
+        fn outputs() -> Vec<(&'static str, TypeId)> {
+            vec![("value", TypeId::of::<i32>())]
+        }
+
+        fn create_handle(gnode: &GraphNode) -> Self::Handle {
+            gnode.handle().clone()
+        }
+    }
+    impl ExecutableNode for Node {
+        fn run(&self, input: &[Box<dyn Any>]) -> Result<Vec<Box<dyn Any>>, NodeRunError> {
+            let value = input[0]
+                .downcast_ref::<i32>()
+                .ok_or_else(|| NodeRunError::from("value has the wrong type"))?;
+            Ok(vec![Box::new(*value)])
+        }
+    }
+
+    assert_eq!(Node::input_type_names(), vec![UNKNOWN_TYPE_NAME]);
+    assert_eq!(Node::output_type_names(), vec![UNKNOWN_TYPE_NAME]);
+
+    let mut graph = ComputeGraph::new();
+    let node = graph.add_node(Node {}, "node".to_string()).unwrap();
+    let gnode = graph.get_node(&node).unwrap();
+    assert_eq!(gnode.get_input_type_names(), [UNKNOWN_TYPE_NAME]);
+    assert_eq!(gnode.get_output_type_names(), [UNKNOWN_TYPE_NAME]);
+}
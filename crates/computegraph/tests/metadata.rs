@@ -34,3 +34,24 @@ fn test_metadata() -> Result<()> {
     assert_eq!(value_node.metadata.get_mut(), Some(&mut OtherMetadata(42)));
     Ok(())
 }
+
+#[test]
+fn test_graph_metadata_shorthand() -> Result<()> {
+    #[derive(Debug, PartialEq, Clone)]
+    struct RenderNode;
+
+    let mut graph = ComputeGraph::new();
+    let value = graph.add_node(TestNodeConstant::new(5), "value".to_string())?;
+
+    assert_eq!(graph.get_metadata::<RenderNode>(&value.handle), None);
+    graph.set_metadata(&value.handle, RenderNode)?;
+    assert_eq!(graph.get_metadata(&value.handle), Some(&RenderNode));
+
+    graph.remove_node(value.clone())?;
+    assert_eq!(graph.get_metadata::<RenderNode>(&value.handle), None);
+    assert!(matches!(
+        graph.set_metadata(&value.handle, RenderNode),
+        Err(MetadataError::NodeNotFound(_))
+    ));
+    Ok(())
+}
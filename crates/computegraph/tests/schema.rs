@@ -0,0 +1,90 @@
+mod common;
+
+use anyhow::Result;
+use common::*;
+use computegraph::*;
+
+fn test_registry() -> NodeRegistry {
+    let mut registry = NodeRegistry::new();
+    registry.register::<TestNodeConstant>("constant", || TestNodeConstant::new(7).into());
+    registry.register::<TestNodeNumToString>("to_string", || TestNodeNumToString::new().into());
+    registry.register_type::<usize>("usize");
+    registry.register_type::<String>("string");
+    registry
+}
+
+#[test]
+fn test_schema_round_trip() -> Result<()> {
+    let mut graph = ComputeGraph::new();
+    let value = graph.add_node(TestNodeConstant::new(7), "value".to_string())?;
+    let to_string = graph.add_node(TestNodeNumToString::new(), "to_string".to_string())?;
+    graph.connect(value.output(), to_string.input())?;
+
+    let registry = test_registry();
+    let json = serde_json::to_string(&graph.to_schema(&registry)?)?;
+
+    let schema: GraphSchema = serde_json::from_str(&json)?;
+    assert_eq!(schema, graph.to_schema(&registry)?);
+    assert_eq!(schema.nodes[0].node_type, "constant");
+    assert_eq!(
+        schema.nodes[0].outputs,
+        vec![PortSchema {
+            name: "output".to_string(),
+            type_tag: "usize".to_string(),
+        }]
+    );
+    let restored = ComputeGraph::from_schema(&schema, &registry)?;
+    assert_eq!(restored.to_schema(&registry)?, schema);
+
+    let output = to_string.output();
+    assert_eq!(restored.compute(output)?, "7".to_string());
+
+    Ok(())
+}
+
+#[test]
+fn test_schema_unknown_node_type() -> Result<()> {
+    let mut graph = ComputeGraph::new();
+    graph.add_node(TestNodeConstant::new(7), "value".to_string())?;
+
+    let schema = graph.to_schema(&test_registry())?;
+    let result = ComputeGraph::from_schema(&schema, &NodeRegistry::new());
+    assert!(matches!(result, Err(SchemaError::UnknownNodeType(_))));
+
+    // Types must be registered to create a schema
+    assert!(matches!(
+        graph.to_schema(&NodeRegistry::new()),
+        Err(SchemaError::UnregisteredNode(name)) if name == "value"
+    ));
+    let mut registry = NodeRegistry::new();
+    registry.register::<TestNodeConstant>("constant", || TestNodeConstant::new(7).into());
+    assert!(matches!(
+        graph.to_schema(&registry),
+        Err(SchemaError::UnregisteredType(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_schema_port_type_changed() -> Result<()> {
+    #[derive(Debug, Clone, PartialEq)]
+    struct TextConstant {}
+
+    #[node(TextConstant)]
+    fn run(&self) -> String {
+        "7".to_string()
+    }
+
+    let mut graph = ComputeGraph::new();
+    graph.add_node(TestNodeConstant::new(7), "value".to_string())?;
+    let schema = graph.to_schema(&test_registry())?;
+
+    // The node type kept its key and port names, but the output type changed
+    let mut registry = test_registry();
+    registry.register::<TextConstant>("constant", || TextConstant {}.into());
+    let result = ComputeGraph::from_schema(&schema, &registry);
+    assert!(matches!(result, Err(SchemaError::PortMismatch(name)) if name == "value"));
+
+    Ok(())
+}
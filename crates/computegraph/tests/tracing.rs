@@ -0,0 +1,83 @@
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use anyhow::Result;
+use common::*;
+use computegraph::*;
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+/// Subscriber recording the node names of all `compute_node` spans.
+#[derive(Default, Clone)]
+struct NodeSpanRecorder {
+    nodes: Arc<Mutex<Vec<String>>>,
+    next_id: Arc<Mutex<u64>>,
+}
+
+struct NodeNameVisitor(Option<String>);
+
+impl Visit for NodeNameVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "node" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+impl Subscriber for NodeSpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+        if span.metadata().name() == "compute_node" {
+            let mut visitor = NodeNameVisitor(None);
+            span.record(&mut visitor);
+            self.nodes.lock().unwrap().extend(visitor.0);
+        }
+        let mut next_id = self.next_id.lock().unwrap();
+        *next_id += 1;
+        span::Id::from_u64(*next_id)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+#[test]
+fn test_tracing_spans() -> Result<()> {
+    let mut graph = ComputeGraph::new();
+
+    let value1 = graph.add_node(TestNodeConstant::new(5), "value1".to_string())?;
+    let value2 = graph.add_node(TestNodeConstant::new(7), "value2".to_string())?;
+    let addition = graph.add_node(TestNodeAddition::new(), "addition".to_string())?;
+    graph.connect(value1.output(), addition.input_a())?;
+    graph.connect(value2.output(), addition.input_b())?;
+
+    let recorder = NodeSpanRecorder::default();
+    let result =
+        tracing::subscriber::with_default(recorder.clone(), || graph.compute(addition.output()))?;
+    assert_eq!(result, 12);
+
+    // Spans are created before the inputs are computed, so dependencies are nested inside
+    assert_eq!(
+        *recorder.nodes.lock().unwrap(),
+        vec!["addition", "value1", "value2"]
+    );
+
+    Ok(())
+}
@@ -9,8 +9,9 @@ use quote::{format_ident, quote};
 use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
-    parse_macro_input, token, Error, FnArg, Ident, ItemFn, Pat, PatType, Receiver, Result,
-    ReturnType, Token, Type, TypeReference, TypeTuple,
+    parse_macro_input, token, Error, Expr, FnArg, GenericArgument, GenericParam, Generics, Ident,
+    ItemFn, Pat, PatType, PathArguments, Receiver, Result, ReturnType, Token, Type, TypeReference,
+    TypeTuple,
 };
 
 /// Parsed arguments passed in the `node` macro.
@@ -20,18 +21,34 @@ struct NodeArgs {
     ///
     /// The run method will be implemented on this struct
     node_name: Ident,
+    /// Generic parameters of the node struct, shared with the generated handle
+    generics: Generics,
     /// Names for how the type returned by `run` should be named
     output_names: OutputNames,
+    /// Set with a trailing `, fallible`, `run` returns a `Result` whose error fails the computation
+    fallible: Option<Ident>,
 }
 
 impl Parse for NodeArgs {
     fn parse(input: ParseStream) -> Result<Self> {
         let node_name: Ident = input.parse()?;
+        let generics: Generics = input.parse()?;
         let output_names = input.parse::<OutputNames>()?;
+        let fallible = if input.parse::<Option<Token![,]>>()?.is_some() {
+            let flag: Ident = input.parse()?;
+            if flag != "fallible" {
+                return Err(Error::new_spanned(flag, "expected `fallible`"));
+            }
+            Some(flag)
+        } else {
+            None
+        };
 
         Ok(Self {
             node_name,
+            generics,
             output_names,
+            fallible,
         })
     }
 }
@@ -76,6 +93,8 @@ impl Parse for OutputNames {
 struct InputArg {
     ident: Ident,
     base_type: Type,
+    /// Value used if the input is not connected, set with `#[default(...)]`
+    default: Option<Expr>,
 }
 
 #[derive(Debug)]
@@ -93,10 +112,35 @@ pub fn node(args: TokenStream, input: TokenStream) -> TokenStream {
 fn node_impl(args: TokenStream, input: TokenStream) -> TokenStream {
     let NodeArgs {
         node_name,
+        generics,
         output_names,
+        fallible,
     } = parse_macro_input!(args as NodeArgs);
 
-    let function = parse_macro_input!(input as ItemFn);
+    // Nodes must be `'static`, so only type and const parameters are supported
+    if let Some(GenericParam::Lifetime(lifetime)) = generics
+        .params
+        .iter()
+        .find(|p| matches!(p, GenericParam::Lifetime(_)))
+    {
+        return Error::new_spanned(lifetime, "nodes can not have lifetime parameters")
+            .to_compile_error()
+            .into();
+    }
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    // The handle does not store values of the type parameters, but needs them for its port types.
+    // Const parameters are carried on the handle without a marker.
+    let type_params: Vec<_> = generics.type_params().map(|p| &p.ident).collect();
+    let (handle_marker_field, handle_marker_init) = if type_params.is_empty() {
+        (quote!(), quote!())
+    } else {
+        (
+            quote!(_marker: ::std::marker::PhantomData<fn() -> (#(#type_params,)*)>,),
+            quote!(_marker: ::std::marker::PhantomData,),
+        )
+    };
+
+    let mut function = parse_macro_input!(input as ItemFn);
     let signature = function.sig.clone();
 
     if signature.ident != "run" {
@@ -147,7 +191,19 @@ fn node_impl(args: TokenStream, input: TokenStream) -> TokenStream {
                 rec_found = true;
             }
             FnArg::Typed(pat_type) => {
-                let PatType { pat, ty, .. } = pat_type;
+                let PatType { pat, ty, attrs, .. } = pat_type;
+                let mut default = None;
+                for attr in attrs.iter().filter(|a| a.path().is_ident("default")) {
+                    if default.is_some() {
+                        return Error::new_spanned(attr, "only one default value can be specified")
+                            .to_compile_error()
+                            .into();
+                    }
+                    match attr.parse_args::<Expr>() {
+                        Ok(expr) => default = Some(expr),
+                        Err(err) => return err.to_compile_error().into(),
+                    }
+                }
                 let base_type = match **ty {
                     Type::Reference(ref r) => {
                         let TypeReference {
@@ -199,6 +255,7 @@ fn node_impl(args: TokenStream, input: TokenStream) -> TokenStream {
                     input_args.push(InputArg {
                         ident: arg_ident,
                         base_type,
+                        default,
                     });
                 } else {
                     return Error::new_spanned(pat, "expected identifier")
@@ -209,6 +266,13 @@ fn node_impl(args: TokenStream, input: TokenStream) -> TokenStream {
         }
     }
 
+    // `#[default(...)]` is not a real attribute, so it must be removed from the generated `run`
+    for input in &mut function.sig.inputs {
+        if let FnArg::Typed(pat_type) = input {
+            pat_type.attrs.retain(|a| !a.path().is_ident("default"));
+        }
+    }
+
     if !rec_found {
         return Error::new_spanned(
             signature.ident,
@@ -220,8 +284,26 @@ fn node_impl(args: TokenStream, input: TokenStream) -> TokenStream {
 
     let mut output_args: Vec<OutputArg> = vec![];
 
+    // Fallible nodes return a `Result`, the outputs are derived from its `Ok` type
+    let return_type = match (fallible.as_ref(), signature.output) {
+        (None, output) => output,
+        (Some(_), ReturnType::Type(arrow, ty)) => match result_ok_type(&ty) {
+            Some(ok_type) => ReturnType::Type(arrow, Box::new(ok_type)),
+            None => {
+                return Error::new_spanned(ty, "fallible nodes must return a `Result<T, E>`")
+                    .to_compile_error()
+                    .into();
+            }
+        },
+        (Some(flag), ReturnType::Default) => {
+            return Error::new_spanned(flag, "fallible nodes must return a `Result<T, E>`")
+                .to_compile_error()
+                .into();
+        }
+    };
+
     // Check if the output types and names are correct
-    match signature.output {
+    match return_type {
         ReturnType::Default => match output_names {
             OutputNames::NotSpecified => {}
             OutputNames::Single(_, token) | OutputNames::Tuple(_, token) => {
@@ -246,8 +328,8 @@ fn node_impl(args: TokenStream, input: TokenStream) -> TokenStream {
                                 // return type of '-> ()'
                             }
                             1 => {
-                                return Error::new_spanned(node_name,
-                                "ambiguous return type, use #[node({node_name} -> output)] (to return the whole tuple) or #[node({node_name} -> (output))] (to return the first element of the tuple)"
+                                return Error::new_spanned(node_name.clone(),
+                                format!("ambiguous return type, use #[node({node_name} -> output)] (to return the whole tuple) or #[node({node_name} -> (output))] (to return the first element of the tuple)")
                                 ).to_compile_error().into();
                             }
                             n => {
@@ -346,11 +428,66 @@ fn node_impl(args: TokenStream, input: TokenStream) -> TokenStream {
         })
         .collect();
 
+    let inputs_type_names = input_args.iter().map(|a| {
+        let in_type = &a.base_type;
+        quote! { ::core::any::type_name::<#in_type>() }
+    });
+    let outputs_type_names = output_args.iter().map(|a| {
+        let ty = &a.base_type;
+        quote! { ::core::any::type_name::<#ty>() }
+    });
+
     let run_call_parameters = 0..input_args.len();
+    let run_call_errors = input_args
+        .iter()
+        .map(|a| format!("input {} is missing or has the wrong type", a.ident));
+    let run_result_error = if fallible.is_some() {
+        quote!(.map_err(::computegraph::NodeRunError::from)?)
+    } else {
+        quote!()
+    };
+
+    let default_inputs: Vec<_> = input_args
+        .iter()
+        .filter_map(|a| {
+            let InputArg {
+                ident,
+                base_type,
+                default,
+            } = a;
+            let input_name = ident.to_string();
+            default.as_ref().map(|default| {
+                quote! {
+                    #input_name => {
+                        let value: #base_type = #default;
+                        ::std::option::Option::Some(::std::boxed::Box::new(value))
+                    }
+                }
+            })
+        })
+        .collect();
+    let default_input_fn = if default_inputs.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            fn default_input(
+                &self,
+                input: &str,
+            ) -> ::std::option::Option<::std::boxed::Box<dyn ::std::any::Any>> {
+                #[allow(clippy::match_single_binding, clippy::single_match_else)]
+                match input {
+                    #(#default_inputs)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    };
 
     let handle_name = format_ident!("{}Handle", node_name);
     let handle_input_ports = input_args.iter().map(|a| {
-        let InputArg { ident, base_type } = a;
+        let InputArg {
+            ident, base_type, ..
+        } = a;
         let fn_ident = if *ident == "input" {
             ident.clone()
         } else {
@@ -400,25 +537,84 @@ fn node_impl(args: TokenStream, input: TokenStream) -> TokenStream {
         }
     };
 
-    quote! {
-        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-        pub struct #handle_name {
-            pub handle: ::computegraph::NodeHandle
+    // Without generics, the handle can use derives. With generics, derives would add unnecessary bounds
+    // on the type parameters, since only the `NodeHandle` is compared.
+    let handle_definition = if generics.params.is_empty() {
+        quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct #handle_name {
+                pub handle: ::computegraph::NodeHandle
+            }
         }
+    } else {
+        let handle_name_str = handle_name.to_string();
+        quote! {
+            pub struct #handle_name #generics {
+                pub handle: ::computegraph::NodeHandle,
+                #handle_marker_field
+            }
+
+            impl #impl_generics ::std::fmt::Debug for #handle_name #ty_generics {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.debug_struct(#handle_name_str)
+                        .field("handle", &self.handle)
+                        .finish()
+                }
+            }
+
+            impl #impl_generics ::std::clone::Clone for #handle_name #ty_generics {
+                fn clone(&self) -> Self {
+                    Self {
+                        handle: self.handle.clone(),
+                        #handle_marker_init
+                    }
+                }
+            }
+
+            impl #impl_generics ::std::cmp::PartialEq for #handle_name #ty_generics {
+                fn eq(&self, other: &Self) -> bool {
+                    self.handle == other.handle
+                }
+            }
+
+            impl #impl_generics ::std::cmp::Eq for #handle_name #ty_generics {}
+
+            impl #impl_generics ::std::cmp::PartialOrd for #handle_name #ty_generics {
+                fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                    ::std::option::Option::Some(self.cmp(other))
+                }
+            }
+
+            impl #impl_generics ::std::cmp::Ord for #handle_name #ty_generics {
+                fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                    self.handle.cmp(&other.handle)
+                }
+            }
 
-        impl #handle_name {
+            impl #impl_generics ::std::hash::Hash for #handle_name #ty_generics {
+                fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                    self.handle.hash(state);
+                }
+            }
+        }
+    };
+
+    quote! {
+        #handle_definition
+
+        impl #impl_generics #handle_name #ty_generics {
             #(#handle_input_ports)*
             #(#handle_output_ports)*
         }
 
-        impl Into<::computegraph::NodeHandle> for #handle_name {
+        impl #impl_generics Into<::computegraph::NodeHandle> for #handle_name #ty_generics {
             fn into(self) -> ::computegraph::NodeHandle {
                 self.handle
             }
         }
 
-        impl ::computegraph::NodeFactory for #node_name {
-            type Handle = #handle_name;
+        impl #impl_generics ::computegraph::NodeFactory for #node_name #ty_generics {
+            type Handle = #handle_name #ty_generics;
 
             fn inputs() -> ::std::vec::Vec<(&'static str, ::core::any::TypeId)> {
                 ::std::vec![
@@ -432,25 +628,58 @@ fn node_impl(args: TokenStream, input: TokenStream) -> TokenStream {
                 ]
             }
 
+            fn input_type_names() -> ::std::vec::Vec<&'static str> {
+                ::std::vec![
+                    #(#inputs_type_names,)*
+                ]
+            }
+
+            fn output_type_names() -> ::std::vec::Vec<&'static str> {
+                ::std::vec![
+                    #(#outputs_type_names,)*
+                ]
+            }
+
             fn create_handle(gnode: &::computegraph::GraphNode) -> Self::Handle {
                 Self::Handle {
                     handle: gnode.handle().clone(),
+                    #handle_marker_init
                 }
             }
         }
 
-        impl ::computegraph::ExecutableNode for #node_name {
-            fn run(&self, input: &[::std::boxed::Box<dyn ::std::any::Any>]) -> Vec<::std::boxed::Box<dyn ::std::any::Any>> {
+        impl #impl_generics ::computegraph::ExecutableNode for #node_name #ty_generics {
+            fn run(
+                &self,
+                input: &[::std::boxed::Box<dyn ::std::any::Any>],
+            ) -> ::std::result::Result<
+                ::std::vec::Vec<::std::boxed::Box<dyn ::std::any::Any>>,
+                ::computegraph::NodeRunError,
+            > {
                 let res = self.run(
-                    #( input[#run_call_parameters].downcast_ref().unwrap() ),*
-                );
-                ::std::vec![
+                    #(
+                        input
+                            .get(#run_call_parameters)
+                            .and_then(|i| i.downcast_ref())
+                            .ok_or_else(|| ::computegraph::NodeRunError::from(#run_call_errors))?
+                    ),*
+                )#run_result_error;
+                ::std::result::Result::Ok(::std::vec![
                     #run_result_to_boxed
-                ]
+                ])
+            }
+
+            #default_input_fn
+
+            fn partial_eq(&self, other: &dyn ::computegraph::ExecutableNode) -> bool {
+                #[allow(unused_imports)]
+                use ::computegraph::__private::{ViaFallback as _, ViaPartialEq as _};
+                let other: &dyn ::std::any::Any = other;
+                (&::computegraph::__private::NodeEq(self)).node_eq(other)
             }
         }
 
-        impl #node_name {
+        impl #impl_generics #node_name #ty_generics {
             #[allow(clippy::missing_const_for_fn)]
             #[allow(clippy::unused_self)]
             #[allow(clippy::trivially_copy_pass_by_ref)]
@@ -460,3 +689,24 @@ fn node_impl(args: TokenStream, input: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+/// Returns `T` if `ty` is a `Result<T, E>`.
+fn result_ok_type(ty: &Type) -> Option<Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    if args.args.len() != 2 {
+        return None;
+    }
+    match args.args.first()? {
+        GenericArgument::Type(ok_type) => Some(ok_type.clone()),
+        _ => None,
+    }
+}
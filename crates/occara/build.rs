@@ -43,14 +43,29 @@ fn main() -> miette::Result<()> {
         println!("cargo:rerun-if-changed={}", entry.path().to_str().unwrap());
     }
 
+    // Optional parts of the C++ wrapper are guarded by preprocessor defines
+    let mut defines = vec![];
+    if std::env::var("CARGO_FEATURE_TEXT").is_ok() {
+        defines.push("OCCARA_TEXT");
+    }
+    if std::env::var("CARGO_FEATURE_STEP").is_ok() {
+        defines.push("OCCARA_STEP");
+    }
+    let mut clang_args = vec!["-std=c++20".to_string()];
+    clang_args.extend(defines.iter().map(|define| format!("-D{define}")));
+    let clang_args: Vec<_> = clang_args.iter().map(String::as_str).collect();
+
     // Generate autocxx bindings
     let mut autocxx_build = autocxx_build::Builder::new(
         "src/ffi.rs",
         [&std::path::PathBuf::from("include"), build.include_dir()],
     )
-    .extra_clang_args(&["-std=c++20"])
+    .extra_clang_args(&clang_args)
     .build()?;
 
+    for define in defines {
+        autocxx_build.define(define, None);
+    }
     autocxx_build
         .std("c++20")
         .files(cpp_files)
@@ -173,6 +173,23 @@ void Transformation::mirror(const Axis &axis) {
   transformation.SetMirror(axis.axis);
 }
 
+void Transformation::translate(const Vector &vector) {
+  transformation.SetTranslation(vector.vector);
+}
+
+void Transformation::rotate(const Axis &axis, Standard_Real angle) {
+  transformation.SetRotation(axis.axis, angle);
+}
+
+void Transformation::scale(const Point &center, Standard_Real factor) {
+  transformation.SetScale(center.point, factor);
+}
+
+Transformation Transformation::then(const Transformation &other) const {
+  // Multiplied applies its argument first
+  return Transformation{other.transformation.Multiplied(transformation)};
+}
+
 // CylindricalSurface
 
 CylindricalSurface CylindricalSurface::create(const PlaneAxis &axis,
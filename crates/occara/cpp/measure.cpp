@@ -0,0 +1,50 @@
+#include "measure.hpp"
+#include "BRepAdaptor_Curve.hxx"
+#include "BRepExtrema_DistShapeShape.hxx"
+#include "BRepGProp.hxx"
+#include "GProp_GProps.hxx"
+
+namespace occara::measure {
+
+// Distance
+
+Distance Distance::between(const shape::Shape &shape1,
+                           const shape::Shape &shape2) {
+  BRepExtrema_DistShapeShape extrema(shape1.shape, shape2.shape);
+  // There may be multiple solutions, all with the same distance
+  return Distance{extrema.Value(), extrema.PointOnShape1(1),
+                  extrema.PointOnShape2(1)};
+}
+
+Distance Distance::clone() const { return *this; }
+
+Standard_Real Distance::get_value() const { return value; }
+
+geom::Point Distance::get_point1() const { return geom::Point{point1}; }
+
+geom::Point Distance::get_point2() const { return geom::Point{point2}; }
+
+// Angle
+
+static gp_Vec start_tangent(const shape::Edge &edge) {
+  BRepAdaptor_Curve curve(edge.edge);
+  gp_Pnt point;
+  gp_Vec tangent;
+  curve.D1(curve.FirstParameter(), point, tangent);
+  return tangent;
+}
+
+Standard_Real angle_between(const shape::Edge &edge1,
+                            const shape::Edge &edge2) {
+  return start_tangent(edge1).Angle(start_tangent(edge2));
+}
+
+// Volume
+
+Standard_Real volume(const shape::Shape &shape) {
+  GProp_GProps properties;
+  BRepGProp::VolumeProperties(shape.shape, properties);
+  return properties.Mass();
+}
+
+} // namespace occara::measure
@@ -1,7 +1,12 @@
 #include "shape.hpp"
+#include "BRepAlgoAPI_Common.hxx"
+#include "BRepAlgoAPI_Cut.hxx"
 #include "BRepAlgoAPI_Fuse.hxx"
 #include "BRepPrimAPI_MakeCylinder.hxx"
 #include <BRepLib.hxx>
+#include <Poly_Triangulation.hxx>
+#include <Standard_Failure.hxx>
+#include <sstream>
 
 namespace occara::shape {
 
@@ -56,12 +61,81 @@ Shape ShellBuilder::build() {
 
 Shape Shape::clone() const { return *this; }
 
+bool Shape::is_null() const { return shape.IsNull(); }
+
+std::string Shape::to_brep() const {
+  std::ostringstream stream;
+  BRepTools::Write(shape, stream);
+  return stream.str();
+}
+
+Shape Shape::from_brep(std::string data) {
+  std::istringstream stream(data);
+  TopoDS_Shape shape;
+  BRep_Builder builder;
+  // Exceptions can not cross the FFI boundary, invalid data results in a null
+  // shape
+  try {
+    BRepTools::Read(shape, stream, builder);
+  } catch (const Standard_Failure &) {
+    return Shape{TopoDS_Shape()};
+  }
+  return Shape{shape};
+}
+
 FilletBuilder Shape::fillet() const {
   return FilletBuilder{BRepFilletAPI_MakeFillet(shape)};
 }
 
+// Exceptions can not cross the FFI boundary, a failed boolean operation
+// results in a null shape
+template <typename Operation>
+static Shape boolean_operation(const TopoDS_Shape &shape1,
+                               const TopoDS_Shape &shape2) {
+  try {
+    Operation operation(shape1, shape2);
+    if (!operation.IsDone() || operation.HasErrors()) {
+      return Shape{TopoDS_Shape()};
+    }
+    return Shape{operation.Shape()};
+  } catch (const Standard_Failure &) {
+    return Shape{TopoDS_Shape()};
+  }
+}
+
 Shape Shape::fuse(const Shape &other) const {
-  return Shape{BRepAlgoAPI_Fuse(shape, other.shape).Shape()};
+  return boolean_operation<BRepAlgoAPI_Fuse>(shape, other.shape);
+}
+
+Shape Shape::cut(const Shape &other) const {
+  return boolean_operation<BRepAlgoAPI_Cut>(shape, other.shape);
+}
+
+Shape Shape::common(const Shape &other) const {
+  return boolean_operation<BRepAlgoAPI_Common>(shape, other.shape);
+}
+
+Shape Shape::transform(
+    const occara::geom::Transformation &transformation) const {
+  BRepBuilderAPI_Transform transform(shape, transformation.transformation);
+  return Shape{transform.Shape()};
+}
+
+bool Shape::bounding_box(bool tight, Standard_Real &x_min,
+                         Standard_Real &y_min, Standard_Real &z_min,
+                         Standard_Real &x_max, Standard_Real &y_max,
+                         Standard_Real &z_max) const {
+  Bnd_Box box;
+  if (tight) {
+    BRepBndLib::AddOptimal(shape, box, Standard_False, Standard_False);
+  } else {
+    BRepBndLib::Add(shape, box);
+  }
+  if (box.IsVoid()) {
+    return false;
+  }
+  box.Get(x_min, y_min, z_min, x_max, y_max, z_max);
+  return true;
 }
 
 Shape Shape::cylinder(const occara::geom::PlaneAxis &axis, Standard_Real radius,
@@ -188,4 +262,65 @@ void Compound::add_shape(const Shape &shape) {
 
 Shape Compound::build() { return Shape{compound}; }
 
+// Mesh
+
+Mesh Mesh::create(const Shape &shape, Standard_Real deflection) {
+  BRepMesh_IncrementalMesh(shape.shape, deflection);
+
+  Mesh mesh;
+  for (TopExp_Explorer explorer(shape.shape, TopAbs_FACE); explorer.More();
+       explorer.Next()) {
+    const TopoDS_Face &face = TopoDS::Face(explorer.Current());
+    TopLoc_Location location;
+    Handle(Poly_Triangulation) triangulation =
+        BRep_Tool::Triangulation(face, location);
+    if (triangulation.IsNull()) {
+      continue;
+    }
+
+    const gp_Trsf &transformation = location.Transformation();
+    const size_t offset = mesh.coordinates.size() / 3;
+    for (Standard_Integer i = 1; i <= triangulation->NbNodes(); ++i) {
+      const gp_Pnt point = triangulation->Node(i).Transformed(transformation);
+      mesh.coordinates.push_back(point.X());
+      mesh.coordinates.push_back(point.Y());
+      mesh.coordinates.push_back(point.Z());
+    }
+
+    // Keep the winding order consistent with the outward facing normal
+    const bool reversed = face.Orientation() == TopAbs_REVERSED;
+    for (Standard_Integer i = 1; i <= triangulation->NbTriangles(); ++i) {
+      Standard_Integer n1, n2, n3;
+      triangulation->Triangle(i).Get(n1, n2, n3);
+      if (reversed) {
+        std::swap(n2, n3);
+      }
+      mesh.indices.push_back(offset + n1 - 1);
+      mesh.indices.push_back(offset + n2 - 1);
+      mesh.indices.push_back(offset + n3 - 1);
+    }
+  }
+  return mesh;
+}
+
+Mesh Mesh::clone() const { return *this; }
+
+size_t Mesh::vertex_count() const { return coordinates.size() / 3; }
+
+void Mesh::get_vertex(size_t index, Standard_Real &x, Standard_Real &y,
+                      Standard_Real &z) const {
+  x = coordinates[3 * index];
+  y = coordinates[3 * index + 1];
+  z = coordinates[3 * index + 2];
+}
+
+size_t Mesh::triangle_count() const { return indices.size() / 3; }
+
+void Mesh::get_triangle(size_t index, size_t &v1, size_t &v2,
+                        size_t &v3) const {
+  v1 = indices[3 * index];
+  v2 = indices[3 * index + 1];
+  v3 = indices[3 * index + 2];
+}
+
 } // namespace occara::shape
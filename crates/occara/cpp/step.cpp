@@ -0,0 +1,39 @@
+#include "step.hpp"
+#ifdef OCCARA_STEP
+#include "IFSelect_ReturnStatus.hxx"
+#include "STEPControl_Reader.hxx"
+#include "STEPControl_Writer.hxx"
+#include "Standard_Failure.hxx"
+
+namespace occara::step {
+
+// Exceptions can not cross the FFI boundary, failures are reported through
+// the return value
+
+bool write_step(const shape::Shape &shape, std::string path) {
+  try {
+    STEPControl_Writer writer;
+    if (writer.Transfer(shape.shape, STEPControl_AsIs) != IFSelect_RetDone) {
+      return false;
+    }
+    return writer.Write(path.c_str()) == IFSelect_RetDone;
+  } catch (const Standard_Failure &) {
+    return false;
+  }
+}
+
+shape::Shape read_step(std::string path) {
+  try {
+    STEPControl_Reader reader;
+    if (reader.ReadFile(path.c_str()) != IFSelect_RetDone) {
+      return shape::Shape{TopoDS_Shape()};
+    }
+    reader.TransferRoots();
+    return shape::Shape{reader.OneShape()};
+  } catch (const Standard_Failure &) {
+    return shape::Shape{TopoDS_Shape()};
+  }
+}
+
+} // namespace occara::step
+#endif
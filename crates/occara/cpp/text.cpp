@@ -0,0 +1,41 @@
+#include "text.hpp"
+#ifdef OCCARA_TEXT
+#include "Font_FontMgr.hxx"
+#include "Standard_Failure.hxx"
+
+namespace occara::text {
+
+// An empty family name selects any available font
+static Font_StrictLevel strict_level(const std::string &family) {
+  return family.empty() ? Font_StrictLevel_Any : Font_StrictLevel_Aliases;
+}
+
+bool Text::is_font_available(std::string family) {
+  Handle(Font_FontMgr) manager = Font_FontMgr::GetInstance();
+  return !manager
+              ->FindFont(TCollection_AsciiString(family.c_str()),
+                         strict_level(family), Font_FontAspect_Regular)
+              .IsNull();
+}
+
+shape::Shape Text::build(std::string text, std::string family,
+                         Standard_Real size,
+                         const geom::PlaneAxis &position) {
+  // Exceptions can not cross the FFI boundary, failures result in a null shape
+  try {
+    Font_BRepFont font;
+    if (!font.FindAndInit(TCollection_AsciiString(family.c_str()),
+                          Font_FontAspect_Regular, size,
+                          strict_level(family))) {
+      return shape::Shape{TopoDS_Shape()};
+    }
+    Font_BRepTextBuilder builder;
+    return shape::Shape{builder.Perform(font, NCollection_String(text.c_str()),
+                                        gp_Ax3(position.axis))};
+  } catch (const Standard_Failure &) {
+    return shape::Shape{TopoDS_Shape()};
+  }
+}
+
+} // namespace occara::text
+#endif
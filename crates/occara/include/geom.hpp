@@ -167,6 +167,10 @@ struct Transformation {
   Transformation clone() const;
 
   void mirror(const Axis &axis);
+  void translate(const Vector &vector);
+  void rotate(const Axis &axis, Standard_Real angle);
+  void scale(const Point &center, Standard_Real factor);
+  Transformation then(const Transformation &other) const;
 };
 
 struct CylindricalSurface {
@@ -0,0 +1,26 @@
+#pragma once
+#include "geom.hpp"
+#include "shape.hpp"
+
+namespace occara::measure {
+
+struct Distance {
+  Standard_Real value;
+  gp_Pnt point1;
+  gp_Pnt point2;
+
+  static Distance between(const shape::Shape &shape1,
+                          const shape::Shape &shape2);
+  Distance clone() const;
+
+  Standard_Real get_value() const;
+  geom::Point get_point1() const;
+  geom::Point get_point2() const;
+};
+
+Standard_Real angle_between(const shape::Edge &edge1,
+                            const shape::Edge &edge2);
+
+Standard_Real volume(const shape::Shape &shape);
+
+} // namespace occara::measure
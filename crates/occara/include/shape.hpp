@@ -1,20 +1,26 @@
 #pragma once
+#include "BRepBndLib.hxx"
 #include "BRepBuilderAPI_MakeEdge.hxx"
 #include "BRepBuilderAPI_MakeFace.hxx"
 #include "BRepBuilderAPI_MakeVertex.hxx"
 #include "BRepBuilderAPI_MakeWire.hxx"
 #include "BRepBuilderAPI_Transform.hxx"
+#include "BRepMesh_IncrementalMesh.hxx"
 #include "BRepFilletAPI_MakeFillet.hxx"
 #include "BRepOffsetAPI_MakeThickSolid.hxx"
 #include "BRepOffsetAPI_ThruSections.hxx"
+#include "BRepTools.hxx"
 #include "BRepPrimAPI_MakePrism.hxx"
 #include "BRep_Tool.hxx"
+#include "Bnd_Box.hxx"
 #include "TopExp_Explorer.hxx"
 #include "TopoDS.hxx"
 #include "TopoDS_Edge.hxx"
 #include "TopoDS_Vertex.hxx"
 #include "TopoDS_Wire.hxx"
 #include "geom.hpp"
+#include <string>
+#include <vector>
 
 namespace occara::shape {
 
@@ -31,6 +37,7 @@ struct Wire;
 struct WireBuilder;
 struct Loft;
 struct Compound;
+struct Mesh;
 
 struct Vertex {
   TopoDS_Vertex vertex;
@@ -70,8 +77,19 @@ struct Shape {
 
   Shape clone() const;
 
+  bool is_null() const;
+  std::string to_brep() const;
+  static Shape from_brep(std::string data);
   FilletBuilder fillet() const;
+  // Boolean operations return a null shape if OCCT fails
   Shape fuse(const Shape &other) const;
+  Shape cut(const Shape &other) const;
+  Shape common(const Shape &other) const;
+  Shape transform(const occara::geom::Transformation &transformation) const;
+  // Returns false if the shape is empty
+  bool bounding_box(bool tight, Standard_Real &x_min, Standard_Real &y_min,
+                    Standard_Real &z_min, Standard_Real &x_max,
+                    Standard_Real &y_max, Standard_Real &z_max) const;
   static Shape cylinder(const occara::geom::PlaneAxis &axis,
                         Standard_Real radius, Standard_Real height);
 };
@@ -161,4 +179,18 @@ struct Compound {
   Shape build();
 };
 
+struct Mesh {
+  std::vector<Standard_Real> coordinates;
+  std::vector<size_t> indices;
+
+  static Mesh create(const Shape &shape, Standard_Real deflection);
+  Mesh clone() const;
+
+  size_t vertex_count() const;
+  void get_vertex(size_t index, Standard_Real &x, Standard_Real &y,
+                  Standard_Real &z) const;
+  size_t triangle_count() const;
+  void get_triangle(size_t index, size_t &v1, size_t &v2, size_t &v3) const;
+};
+
 } // namespace occara::shape
@@ -0,0 +1,13 @@
+#pragma once
+// STEP support requires OCCT to be built with DataExchange, see the `step` feature
+#ifdef OCCARA_STEP
+#include "shape.hpp"
+#include <string>
+
+namespace occara::step {
+
+bool write_step(const shape::Shape &shape, std::string path);
+shape::Shape read_step(std::string path);
+
+} // namespace occara::step
+#endif
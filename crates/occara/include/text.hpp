@@ -0,0 +1,20 @@
+#pragma once
+// Text geometry requires OCCT to be built with FreeType, see the `text` feature
+#ifdef OCCARA_TEXT
+#include "Font_BRepFont.hxx"
+#include "Font_BRepTextBuilder.hxx"
+#include "geom.hpp"
+#include "shape.hpp"
+#include <string>
+
+namespace occara::text {
+
+struct Text {
+  static bool is_font_available(std::string family);
+  static shape::Shape build(std::string text, std::string family,
+                            Standard_Real size,
+                            const geom::PlaneAxis &position);
+};
+
+} // namespace occara::text
+#endif
@@ -10,6 +10,9 @@
 autocxx::include_cpp! {
     #include "shape.hpp"
     #include "geom.hpp"
+    #include "measure.hpp"
+    #include "text.hpp"
+    #include "step.hpp"
     #include "MakeBottle.hpp"
     safety!(unsafe)
     generate_ns!("occara")
@@ -352,6 +352,38 @@ impl Transformation {
         Self(transformation)
     }
 
+    /// Creates a transformation moving objects by `vector`.
+    #[must_use]
+    pub fn translation(vector: &Vector) -> Self {
+        let mut transformation = ffi_geom::Transformation::new().within_box();
+        transformation.as_mut().translate(&vector.0);
+        Self(transformation)
+    }
+
+    /// Creates a transformation rotating objects around `axis` by `angle` radians.
+    ///
+    /// Positive angles rotate counter-clockwise when looking against the direction of `axis`.
+    #[must_use]
+    pub fn rotation(axis: &Axis, angle: f64) -> Self {
+        let mut transformation = ffi_geom::Transformation::new().within_box();
+        transformation.as_mut().rotate(&axis.0, angle);
+        Self(transformation)
+    }
+
+    /// Creates a transformation scaling objects uniformly by `factor` around `center`.
+    #[must_use]
+    pub fn scale(center: &Point, factor: f64) -> Self {
+        let mut transformation = ffi_geom::Transformation::new().within_box();
+        transformation.as_mut().scale(&center.0, factor);
+        Self(transformation)
+    }
+
+    /// Combines two transformations into one, applying `self` first and `other` second.
+    #[must_use]
+    pub fn then(&self, other: &Self) -> Self {
+        Self(self.0.then(&other.0).within_box())
+    }
+
     #[must_use]
     pub fn apply<T: Transformable>(&self, object: &T) -> T {
         object.transform(self)
@@ -6,7 +6,13 @@
 mod ffi;
 
 pub mod geom;
+pub mod measure;
+pub mod mesh;
 pub mod shape;
+#[cfg(feature = "step")]
+pub mod step;
+#[cfg(feature = "text")]
+pub mod text;
 
 #[doc(hidden)]
 pub mod internal {
@@ -0,0 +1,34 @@
+use crate::ffi::occara::measure as ffi_measure;
+use crate::geom::Point;
+use crate::shape::{Edge, Shape};
+use autocxx::prelude::*;
+
+/// Computes the minimum distance between two shapes.
+///
+/// Returns the distance together with the closest points on `a` and `b`.
+/// If multiple pairs of points have the minimum distance, any of them is returned.
+#[must_use]
+pub fn distance(a: &Shape, b: &Shape) -> (f64, Point, Point) {
+    let distance = ffi_measure::Distance::between(&a.0, &b.0).within_box();
+    (
+        distance.get_value(),
+        Point(distance.get_point1().within_box()),
+        Point(distance.get_point2().within_box()),
+    )
+}
+
+/// Computes the angle in radians between the tangents at the start of two edges.
+///
+/// The result is in the range `[0, π]`. For straight edges, this is the angle between their directions.
+#[must_use]
+pub fn angle_between(e1: &Edge, e2: &Edge) -> f64 {
+    ffi_measure::angle_between(&e1.0, &e2.0)
+}
+
+/// Computes the volume enclosed by the solids of a shape.
+///
+/// Shapes without solids, like faces or wires, have a volume of zero.
+#[must_use]
+pub fn volume(shape: &Shape) -> f64 {
+    ffi_measure::volume(&shape.0)
+}
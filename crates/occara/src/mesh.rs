@@ -0,0 +1,152 @@
+use std::fmt::Write;
+
+/// A triangle mesh, usually obtained by tessellating a [`crate::shape::Shape`].
+///
+/// Triangles are stored as indices into `vertices`, wound counter-clockwise
+/// when viewed from outside of the shape.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Mesh {
+    pub vertices: Vec<[f64; 3]>,
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl Mesh {
+    /// Returns the unit normal of the triangle at `index`, derived from its winding order.
+    ///
+    /// Degenerate triangles have a zero normal.
+    #[must_use]
+    pub fn triangle_normal(&self, index: usize) -> [f64; 3] {
+        let [p1, p2, p3] = self.triangles[index].map(|i| self.vertices[i]);
+        let e1 = [p2[0] - p1[0], p2[1] - p1[1], p2[2] - p1[2]];
+        let e2 = [p3[0] - p1[0], p3[1] - p1[1], p3[2] - p1[2]];
+        let normal = [
+            e1[1].mul_add(e2[2], -(e1[2] * e2[1])),
+            e1[2].mul_add(e2[0], -(e1[0] * e2[2])),
+            e1[0].mul_add(e2[1], -(e1[1] * e2[0])),
+        ];
+        let length = normal[0]
+            .mul_add(
+                normal[0],
+                normal[1].mul_add(normal[1], normal[2] * normal[2]),
+            )
+            .sqrt();
+        if length > 0.0 {
+            normal.map(|x| x / length)
+        } else {
+            [0.0; 3]
+        }
+    }
+
+    /// Returns a unit normal for each vertex, averaged over all triangles using it.
+    ///
+    /// Triangles are weighted by their area. Since [`crate::shape::Shape::triangulate`] does not share
+    /// vertices between faces, this gives smooth shading within a face while keeping edges between faces sharp.
+    /// Vertices not used by any triangle have a zero normal.
+    #[must_use]
+    pub fn vertex_normals(&self) -> Vec<[f64; 3]> {
+        let mut normals = vec![[0.0; 3]; self.vertices.len()];
+        for triangle in &self.triangles {
+            let [p1, p2, p3] = triangle.map(|i| self.vertices[i]);
+            let e1 = [p2[0] - p1[0], p2[1] - p1[1], p2[2] - p1[2]];
+            let e2 = [p3[0] - p1[0], p3[1] - p1[1], p3[2] - p1[2]];
+            // The length of the cross product is twice the area of the triangle
+            let weighted_normal = [
+                e1[1].mul_add(e2[2], -(e1[2] * e2[1])),
+                e1[2].mul_add(e2[0], -(e1[0] * e2[2])),
+                e1[0].mul_add(e2[1], -(e1[1] * e2[0])),
+            ];
+            for &vertex in triangle {
+                for (n, w) in normals[vertex].iter_mut().zip(weighted_normal) {
+                    *n += w;
+                }
+            }
+        }
+        for normal in &mut normals {
+            let length = normal[0]
+                .mul_add(
+                    normal[0],
+                    normal[1].mul_add(normal[1], normal[2] * normal[2]),
+                )
+                .sqrt();
+            if length > 0.0 {
+                *normal = normal.map(|x| x / length);
+            }
+        }
+        normals
+    }
+
+    /// Converts the mesh to the single precision buffers used for rendering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mesh has more than `u32::MAX` vertices.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_render_mesh(&self) -> RenderMesh {
+        assert!(
+            u32::try_from(self.vertices.len()).is_ok(),
+            "too many vertices for 32 bit indices"
+        );
+        RenderMesh {
+            positions: self.vertices.iter().map(|v| v.map(|x| x as f32)).collect(),
+            normals: self
+                .vertex_normals()
+                .iter()
+                .map(|n| n.map(|x| x as f32))
+                .collect(),
+            indices: self.triangles.iter().flatten().map(|&i| i as u32).collect(),
+        }
+    }
+
+    /// Encodes the mesh as a binary STL file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mesh has more than `u32::MAX` triangles.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_stl_binary(&self) -> Vec<u8> {
+        let count = u32::try_from(self.triangles.len()).expect("too many triangles for STL");
+        let mut stl = Vec::with_capacity(84 + 50 * self.triangles.len());
+        stl.extend_from_slice(&[0; 80]);
+        stl.extend_from_slice(&count.to_le_bytes());
+        for (index, triangle) in self.triangles.iter().enumerate() {
+            let normal = self.triangle_normal(index);
+            let points = triangle.map(|i| self.vertices[i]);
+            for value in normal.iter().chain(points.iter().flatten()) {
+                stl.extend_from_slice(&(*value as f32).to_le_bytes());
+            }
+            // Attribute byte count, unused
+            stl.extend_from_slice(&0u16.to_le_bytes());
+        }
+        stl
+    }
+
+    /// Encodes the mesh as an ASCII STL file.
+    #[must_use]
+    pub fn to_stl_ascii(&self) -> String {
+        let mut stl = String::from("solid occara\n");
+        for (index, triangle) in self.triangles.iter().enumerate() {
+            let [nx, ny, nz] = self.triangle_normal(index);
+            // Writing to a String can not fail
+            let _ = writeln!(stl, "  facet normal {nx:e} {ny:e} {nz:e}");
+            stl.push_str("    outer loop\n");
+            for [x, y, z] in triangle.map(|i| self.vertices[i]) {
+                let _ = writeln!(stl, "      vertex {x:e} {y:e} {z:e}");
+            }
+            stl.push_str("    endloop\n  endfacet\n");
+        }
+        stl.push_str("endsolid occara\n");
+        stl
+    }
+}
+
+/// A triangle mesh in the layout expected by GPU vertex and index buffers, see [`Mesh::to_render_mesh`].
+///
+/// `positions` and `normals` have one entry per vertex, `indices` has three entries per triangle.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RenderMesh {
+    pub positions: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    pub indices: Vec<u32>,
+}
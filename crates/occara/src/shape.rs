@@ -1,6 +1,9 @@
 use super::ffi::occara::shape as ffi_shape;
 use crate::geom;
+use crate::mesh::Mesh;
 use autocxx::prelude::*;
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+use std::io::{Read, Write};
 use std::pin::Pin;
 
 pub struct Vertex(pub(crate) Pin<Box<ffi_shape::Vertex>>);
@@ -66,6 +69,70 @@ impl Shape {
         Self(self.0.fuse(&other.0).within_box())
     }
 
+    /// Combines this shape with `other` into a shape containing the volume of both.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OccError::BooleanOperationFailed`] if OpenCASCADE could not compute the result.
+    pub fn union(&self, other: &Self) -> Result<Self, OccError> {
+        Self(self.0.fuse(&other.0).within_box()).non_null(OccError::BooleanOperationFailed)
+    }
+
+    /// Removes the volume of `other` from this shape.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OccError::BooleanOperationFailed`] if OpenCASCADE could not compute the result.
+    pub fn difference(&self, other: &Self) -> Result<Self, OccError> {
+        Self(self.0.cut(&other.0).within_box()).non_null(OccError::BooleanOperationFailed)
+    }
+
+    /// Returns the volume shared by this shape and `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OccError::BooleanOperationFailed`] if OpenCASCADE could not compute the result.
+    pub fn intersection(&self, other: &Self) -> Result<Self, OccError> {
+        Self(self.0.common(&other.0).within_box()).non_null(OccError::BooleanOperationFailed)
+    }
+
+    /// Returns the minimum and maximum corners of a box enclosing this shape.
+    ///
+    /// If `tight` is `false`, the box is computed quickly, but may be larger than the shape, e.g. by
+    /// including the tolerance of its edges or the control points of curved surfaces. With `tight` set to
+    /// `true`, the exact extent of the geometry is computed, which is more expensive.
+    ///
+    /// Returns `None` for empty shapes.
+    #[must_use]
+    pub fn bounding_box(&self, tight: bool) -> Option<(geom::Point, geom::Point)> {
+        let (mut x_min, mut y_min, mut z_min) = (0.0, 0.0, 0.0);
+        let (mut x_max, mut y_max, mut z_max) = (0.0, 0.0, 0.0);
+        let found = self.0.bounding_box(
+            tight,
+            Pin::new(&mut x_min),
+            Pin::new(&mut y_min),
+            Pin::new(&mut z_min),
+            Pin::new(&mut x_max),
+            Pin::new(&mut y_max),
+            Pin::new(&mut z_max),
+        );
+        found.then(|| {
+            (
+                geom::Point::new(x_min, y_min, z_min),
+                geom::Point::new(x_max, y_max, z_max),
+            )
+        })
+    }
+
+    /// Returns `error` if this is a null shape, which the C++ wrapper returns for failed operations.
+    fn non_null(self, error: OccError) -> Result<Self, OccError> {
+        if self.0.is_null() {
+            Err(error)
+        } else {
+            Ok(self)
+        }
+    }
+
     #[must_use]
     pub fn shell(&self) -> ShellBuilder {
         ShellBuilder(ffi_shape::ShellBuilder::create(&self.0).within_box())
@@ -75,14 +142,141 @@ impl Shape {
     pub fn cylinder(axis: &geom::PlaneAxis, radius: f64, height: f64) -> Self {
         Self(ffi_shape::Shape::cylinder(&axis.0.as_ref(), radius, height).within_box())
     }
+
+    /// Serializes this shape to OpenCASCADE's native BREP format.
+    #[must_use]
+    pub fn to_brep(&self) -> Vec<u8> {
+        self.0.to_brep().as_bytes().to_vec()
+    }
+
+    /// Reads a shape from data written by [`Shape::to_brep`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrepError::InvalidData`] if `data` is not a valid BREP file.
+    pub fn from_brep(data: &[u8]) -> Result<Self, BrepError> {
+        let data = std::str::from_utf8(data).map_err(|_| BrepError::InvalidData)?;
+        let shape = Self(ffi_shape::Shape::from_brep(data).within_box());
+        if shape.0.is_null() {
+            Err(BrepError::InvalidData)
+        } else {
+            Ok(shape)
+        }
+    }
+
+    /// Serializes this shape to the BREP format, compressed with zlib.
+    ///
+    /// BREP is a verbose text format, so this is usually much smaller than [`Shape::to_brep`].
+    ///
+    /// # Panics
+    ///
+    /// This function is not expected to panic under normal circumstances.
+    #[must_use]
+    pub fn to_brep_compressed(&self) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        // Writing into a Vec can not fail
+        encoder.write_all(&self.to_brep()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    /// Reads a shape from data written by [`Shape::to_brep_compressed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` could not be decompressed or is not a valid BREP file.
+    pub fn from_brep_compressed(data: &[u8]) -> Result<Self, BrepError> {
+        let mut brep = Vec::new();
+        ZlibDecoder::new(data)
+            .read_to_end(&mut brep)
+            .map_err(|e| BrepError::Decompression(e.to_string()))?;
+        Self::from_brep(&brep)
+    }
+
+    /// Tessellates the faces of this shape into a triangle mesh.
+    ///
+    /// `deflection` is the maximum allowed distance between the mesh and the actual surface.
+    #[must_use]
+    pub fn triangulate(&self, deflection: f64) -> Mesh {
+        let mesh = ffi_shape::Mesh::create(&self.0, deflection).within_box();
+        let vertices = (0..mesh.vertex_count())
+            .map(|i| {
+                let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+                mesh.get_vertex(i, Pin::new(&mut x), Pin::new(&mut y), Pin::new(&mut z));
+                [x, y, z]
+            })
+            .collect();
+        let triangles = (0..mesh.triangle_count())
+            .map(|i| {
+                let (mut v1, mut v2, mut v3) = (0, 0, 0);
+                mesh.get_triangle(i, Pin::new(&mut v1), Pin::new(&mut v2), Pin::new(&mut v3));
+                [v1, v2, v3]
+            })
+            .collect();
+        Mesh {
+            vertices,
+            triangles,
+        }
+    }
+}
+
+/// The error that can occur when reading a shape from BREP data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrepError {
+    /// The data is not a valid BREP file.
+    InvalidData,
+    /// The compressed data could not be decompressed.
+    Decompression(String),
+}
+
+impl std::fmt::Display for BrepError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidData => write!(f, "invalid BREP data"),
+            Self::Decompression(error) => write!(f, "failed to decompress BREP data: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for BrepError {}
+
+/// The error that can occur when an OpenCASCADE operation fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OccError {
+    /// A boolean operation like [`Shape::union`] did not produce a result.
+    BooleanOperationFailed,
+    /// The path can not be passed to OpenCASCADE, since it is not valid UTF-8.
+    InvalidPath,
+    /// The shape could not be written to a STEP file.
+    StepExportFailed,
+    /// The file could not be read or does not contain a valid STEP model.
+    StepImportFailed,
+}
+
+impl std::fmt::Display for OccError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BooleanOperationFailed => write!(f, "boolean operation failed"),
+            Self::InvalidPath => write!(f, "path is not valid UTF-8"),
+            Self::StepExportFailed => write!(f, "failed to export STEP file"),
+            Self::StepImportFailed => write!(f, "failed to import STEP file"),
+        }
+    }
 }
 
+impl std::error::Error for OccError {}
+
 impl Clone for Shape {
     fn clone(&self) -> Self {
         Self(self.0.clone().within_box())
     }
 }
 
+impl geom::Transformable for Shape {
+    fn transform(&self, transformation: &geom::Transformation) -> Self {
+        Self(self.0.transform(&transformation.0).within_box())
+    }
+}
+
 pub struct EdgeIterator(pub(crate) Pin<Box<ffi_shape::EdgeIterator>>);
 
 impl Iterator for EdgeIterator {
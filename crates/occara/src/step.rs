@@ -0,0 +1,35 @@
+use crate::ffi::occara::step as ffi_step;
+use crate::shape::{OccError, Shape};
+use autocxx::prelude::*;
+use std::path::Path;
+
+impl Shape {
+    /// Writes this shape to a STEP file at `path`, replacing an existing file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` is not valid UTF-8 or the file could not be written.
+    pub fn export_step(&self, path: &Path) -> Result<(), OccError> {
+        let path = path.to_str().ok_or(OccError::InvalidPath)?;
+        if ffi_step::write_step(&self.0, path) {
+            Ok(())
+        } else {
+            Err(OccError::StepExportFailed)
+        }
+    }
+
+    /// Reads all shapes of the STEP file at `path` into a single shape.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` is not valid UTF-8, or the file could not be read or contains no shapes.
+    pub fn import_step(path: &Path) -> Result<Self, OccError> {
+        let path = path.to_str().ok_or(OccError::InvalidPath)?;
+        let shape = Self(ffi_step::read_step(path).within_box());
+        if shape.0.is_null() {
+            Err(OccError::StepImportFailed)
+        } else {
+            Ok(shape)
+        }
+    }
+}
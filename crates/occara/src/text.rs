@@ -0,0 +1,87 @@
+use crate::ffi::occara::text as ffi_text;
+use crate::geom;
+use crate::shape::Shape;
+use autocxx::prelude::*;
+
+/// A font used to generate text geometry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Font {
+    family: String,
+}
+
+impl Font {
+    /// Selects a system font by its family name (e.g. "DejaVu Sans").
+    #[must_use]
+    pub fn new(family: &str) -> Self {
+        Self {
+            family: family.to_string(),
+        }
+    }
+
+    /// Selects any font installed on the system.
+    #[must_use]
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if a matching font is installed.
+    #[must_use]
+    pub fn is_available(&self) -> bool {
+        ffi_text::Text::is_font_available(&self.family)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextError {
+    /// No font matching the requested family is installed.
+    FontNotFound(String),
+    /// No fonts are installed at all.
+    NoFontAvailable,
+    /// OpenCASCADE failed to generate geometry for the text.
+    BuildFailed,
+}
+
+impl std::fmt::Display for TextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FontNotFound(family) => write!(f, "font '{family}' was not found"),
+            Self::NoFontAvailable => write!(f, "no fonts are available on this system"),
+            Self::BuildFailed => write!(f, "failed to build text geometry"),
+        }
+    }
+}
+
+impl std::error::Error for TextError {}
+
+impl Shape {
+    /// Generates planar faces for each glyph of `text`.
+    ///
+    /// The text starts at the origin of `position`, lying in its XY plane. `size` is the font size
+    /// in model units.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `font` is not installed or the geometry could not be built.
+    pub fn text(
+        text: &str,
+        font: &Font,
+        size: f64,
+        position: &geom::PlaneAxis,
+    ) -> Result<Self, TextError> {
+        if !font.is_available() {
+            return Err(if font.family.is_empty() {
+                TextError::NoFontAvailable
+            } else {
+                TextError::FontNotFound(font.family.clone())
+            });
+        }
+        let shape = Self(
+            ffi_text::Text::build(text, &font.family, size, &position.0.as_ref()).within_box(),
+        );
+        if shape.0.is_null() {
+            Err(TextError::BuildFailed)
+        } else {
+            Ok(shape)
+        }
+    }
+}
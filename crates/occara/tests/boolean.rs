@@ -0,0 +1,37 @@
+use occara::geom::{Direction, Point};
+use occara::measure::volume;
+use occara::shape::Shape;
+use std::f64::consts::PI;
+
+const TOLERANCE: f64 = 1e-6;
+
+/// Two cylinders with radius 1 on the z axis, spanning `0..2` and `1..3`.
+fn overlapping_cylinders() -> (Shape, Shape) {
+    let axis1 = Point::origin().plane_axis_with(&Direction::z());
+    let axis2 = Point::new(0.0, 0.0, 1.0).plane_axis_with(&Direction::z());
+    (
+        Shape::cylinder(&axis1, 1.0, 2.0),
+        Shape::cylinder(&axis2, 1.0, 2.0),
+    )
+}
+
+#[test]
+fn test_union() {
+    let (a, b) = overlapping_cylinders();
+    let union = a.union(&b).unwrap();
+    assert!((volume(&union) - 3.0 * PI).abs() < TOLERANCE);
+}
+
+#[test]
+fn test_difference() {
+    let (a, b) = overlapping_cylinders();
+    let difference = a.difference(&b).unwrap();
+    assert!((volume(&difference) - PI).abs() < TOLERANCE);
+}
+
+#[test]
+fn test_intersection() {
+    let (a, b) = overlapping_cylinders();
+    let intersection = a.intersection(&b).unwrap();
+    assert!((volume(&intersection) - PI).abs() < TOLERANCE);
+}
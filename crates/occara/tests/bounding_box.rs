@@ -0,0 +1,29 @@
+use occara::internal::make_bottle_cpp;
+use occara::shape::Compound;
+
+#[test]
+fn test_bottle_bounding_box() {
+    let (width, height, thickness) = (50.0, 70.0, 30.0);
+    let bottle = make_bottle_cpp(width, height, thickness);
+
+    let (min, max) = bottle.bounding_box(true).unwrap();
+    let tolerance = 1e-3;
+    assert!((min.x() - -width / 2.0).abs() < tolerance);
+    assert!((max.x() - width / 2.0).abs() < tolerance);
+    assert!((min.y() - -thickness / 2.0).abs() < tolerance);
+    assert!((max.y() - thickness / 2.0).abs() < tolerance);
+    assert!(min.z().abs() < tolerance);
+    // The neck adds another tenth of the height
+    assert!((max.z() - height * 1.1).abs() < tolerance);
+
+    // The fast box is never smaller than the tight one
+    let (fast_min, fast_max) = bottle.bounding_box(false).unwrap();
+    assert!(fast_min.x() <= min.x() && fast_min.y() <= min.y() && fast_min.z() <= min.z());
+    assert!(fast_max.x() >= max.x() && fast_max.y() >= max.y() && fast_max.z() >= max.z());
+}
+
+#[test]
+fn test_empty_bounding_box() {
+    let empty = Compound::builder().build();
+    assert!(empty.bounding_box(false).is_none());
+}
@@ -0,0 +1,43 @@
+use occara::geom::{Direction, Point};
+use occara::shape::{BrepError, Shape};
+
+fn sample_shape() -> Shape {
+    let axis = Point::origin().plane_axis_with(&Direction::z());
+    let neck_axis = Point::new(0.0, 0.0, 2.0).plane_axis_with(&Direction::z());
+    Shape::cylinder(&axis, 2.0, 2.0).fuse(&Shape::cylinder(&neck_axis, 0.5, 1.0))
+}
+
+#[test]
+fn test_brep_round_trip() {
+    let shape = sample_shape();
+    let brep = shape.to_brep();
+
+    let read = Shape::from_brep(&brep).unwrap();
+
+    assert_eq!(read.faces().count(), shape.faces().count());
+    assert_eq!(read.edges().count(), shape.edges().count());
+    assert_eq!(read.to_brep(), brep);
+}
+
+#[test]
+fn test_brep_compressed_round_trip() {
+    let shape = sample_shape();
+    let compressed = shape.to_brep_compressed();
+
+    assert!(compressed.len() < shape.to_brep().len());
+
+    let read = Shape::from_brep_compressed(&compressed).unwrap();
+    assert_eq!(read.to_brep(), shape.to_brep());
+}
+
+#[test]
+fn test_brep_invalid_data() {
+    assert_eq!(
+        Shape::from_brep(b"not a brep file").err(),
+        Some(BrepError::InvalidData)
+    );
+    assert!(matches!(
+        Shape::from_brep_compressed(b"not compressed"),
+        Err(BrepError::Decompression(_))
+    ));
+}
@@ -0,0 +1,46 @@
+use occara::geom::{Direction, Point};
+use occara::measure::{angle_between, distance};
+use occara::shape::{Edge, Shape};
+use std::f64::consts::PI;
+
+const TOLERANCE: f64 = 1e-6;
+
+#[test]
+fn test_distance_between_cylinders() {
+    let axis1 = Point::origin().plane_axis_with(&Direction::z());
+    let axis2 = Point::new(10.0, 0.0, 0.0).plane_axis_with(&Direction::z());
+    let cylinder1 = Shape::cylinder(&axis1, 1.0, 2.0);
+    let cylinder2 = Shape::cylinder(&axis2, 1.0, 2.0);
+
+    let (value, point1, point2) = distance(&cylinder1, &cylinder2);
+
+    assert!((value - 8.0).abs() < TOLERANCE);
+    assert!((point1.x() - 1.0).abs() < TOLERANCE);
+    assert!((point2.x() - 9.0).abs() < TOLERANCE);
+}
+
+#[test]
+fn test_distance_between_intersecting_shapes() {
+    let axis1 = Point::origin().plane_axis_with(&Direction::z());
+    let axis2 = Point::new(1.0, 0.0, 0.0).plane_axis_with(&Direction::z());
+    let cylinder1 = Shape::cylinder(&axis1, 1.0, 2.0);
+    let cylinder2 = Shape::cylinder(&axis2, 1.0, 2.0);
+
+    let (value, _, _) = distance(&cylinder1, &cylinder2);
+
+    assert!(value.abs() < TOLERANCE);
+}
+
+#[test]
+fn test_angle_between_edges() {
+    let origin = Point::origin();
+    let x_axis = Edge::line(&origin, &Point::new(1.0, 0.0, 0.0));
+    let y_axis = Edge::line(&origin, &Point::new(0.0, 2.0, 0.0));
+    let diagonal = Edge::line(&origin, &Point::new(1.0, 1.0, 0.0));
+    let reversed = Edge::line(&Point::new(1.0, 0.0, 0.0), &origin);
+
+    assert!((angle_between(&x_axis, &y_axis) - PI / 2.0).abs() < TOLERANCE);
+    assert!((angle_between(&x_axis, &diagonal) - PI / 4.0).abs() < TOLERANCE);
+    assert!((angle_between(&x_axis, &reversed) - PI).abs() < TOLERANCE);
+    assert!(angle_between(&x_axis, &x_axis).abs() < TOLERANCE);
+}
@@ -0,0 +1,41 @@
+use occara::geom::{Direction, Point};
+use occara::mesh::Mesh;
+use occara::shape::Shape;
+
+#[test]
+fn test_vertex_normals() {
+    // Two triangles folded along the x axis, one in the xy and one in the xz plane
+    let mesh = Mesh {
+        vertices: vec![
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, -1.0],
+        ],
+        triangles: vec![[0, 1, 2], [0, 3, 1]],
+    };
+    let normals = mesh.vertex_normals();
+
+    let diagonal = 1.0 / 2.0_f64.sqrt();
+    assert_eq!(normals.len(), 4);
+    assert!((normals[0][1] - -diagonal).abs() < 1e-9);
+    assert!((normals[0][2] - diagonal).abs() < 1e-9);
+    assert_eq!(normals[2], [0.0, 0.0, 1.0]);
+    assert_eq!(normals[3], [0.0, -1.0, 0.0]);
+}
+
+#[test]
+fn test_render_mesh_from_shape() {
+    let axis = Point::origin().plane_axis_with(&Direction::z());
+    let cylinder = Shape::cylinder(&axis, 1.0, 2.0);
+    let render_mesh = cylinder.triangulate(0.01).to_render_mesh();
+
+    assert!(!render_mesh.positions.is_empty());
+    assert_eq!(render_mesh.normals.len(), render_mesh.positions.len());
+    assert!(!render_mesh.indices.is_empty());
+    assert_eq!(render_mesh.indices.len() % 3, 0);
+    assert!(render_mesh
+        .indices
+        .iter()
+        .all(|&i| (i as usize) < render_mesh.positions.len()));
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "step")]
+use occara::internal::make_bottle_cpp;
+use occara::measure::volume;
+use occara::shape::{OccError, Shape};
+
+#[test]
+fn test_step_round_trip() {
+    let bottle = make_bottle_cpp(50.0, 70.0, 30.0);
+    let path = std::env::temp_dir().join(format!("occara-bottle-{}.step", std::process::id()));
+
+    bottle.export_step(&path).unwrap();
+    let read = Shape::import_step(&path);
+    std::fs::remove_file(&path).unwrap();
+    let read = read.unwrap();
+
+    assert_eq!(read.faces().count(), bottle.faces().count());
+    assert!((volume(&read) - volume(&bottle)).abs() / volume(&bottle) < 1e-6);
+}
+
+#[test]
+fn test_step_import_missing_file() {
+    let path = std::env::temp_dir().join("occara-does-not-exist.step");
+    assert_eq!(
+        Shape::import_step(&path).err(),
+        Some(OccError::StepImportFailed)
+    );
+}
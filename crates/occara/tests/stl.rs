@@ -0,0 +1,90 @@
+use occara::geom::{Direction, Point};
+use occara::mesh::Mesh;
+use occara::shape::Shape;
+
+fn single_triangle() -> Mesh {
+    Mesh {
+        vertices: vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        triangles: vec![[0, 1, 2]],
+    }
+}
+
+fn read_f32(bytes: &[u8], offset: usize) -> f32 {
+    f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+#[test]
+fn test_triangle_normal() {
+    let mesh = single_triangle();
+    assert_eq!(mesh.triangle_normal(0), [0.0, 0.0, 1.0]);
+
+    let flipped = Mesh {
+        triangles: vec![[0, 2, 1]],
+        ..single_triangle()
+    };
+    assert_eq!(flipped.triangle_normal(0), [0.0, 0.0, -1.0]);
+}
+
+#[test]
+fn test_stl_binary_layout() {
+    let stl = single_triangle().to_stl_binary();
+
+    assert_eq!(stl.len(), 84 + 50);
+    assert!(stl[..80].iter().all(|&b| b == 0));
+    assert_eq!(u32::from_le_bytes(stl[80..84].try_into().unwrap()), 1);
+
+    let floats: Vec<f32> = (0..12).map(|i| read_f32(&stl, 84 + 4 * i)).collect();
+    assert_eq!(
+        floats,
+        [0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]
+    );
+    assert_eq!(&stl[132..134], &[0, 0]);
+}
+
+#[test]
+fn test_stl_ascii() {
+    let stl = single_triangle().to_stl_ascii();
+
+    assert!(stl.starts_with("solid occara\n"));
+    assert!(stl.ends_with("endsolid occara\n"));
+    assert_eq!(stl.matches("facet normal 0e0 0e0 1e0").count(), 1);
+    assert_eq!(stl.matches("vertex").count(), 3);
+}
+
+#[test]
+fn test_stl_from_shape() {
+    let axis = Point::origin().plane_axis_with(&Direction::z());
+    let cylinder = Shape::cylinder(&axis, 1.0, 2.0);
+    let mesh = cylinder.triangulate(0.01);
+
+    assert!(!mesh.triangles.is_empty());
+    let (mut bottom, mut top, mut side) = (0, 0, 0);
+    for (index, triangle) in mesh.triangles.iter().enumerate() {
+        let [x, y, z] = mesh.triangle_normal(index);
+        assert!((x.mul_add(x, y.mul_add(y, z * z)) - 1.0).abs() < 1e-6);
+
+        // All normals must point out of the cylinder, including those of reversed faces
+        let vertices = triangle.map(|i| mesh.vertices[i]);
+        let centroid: [f64; 3] = std::array::from_fn(|coordinate| {
+            vertices.iter().map(|v| v[coordinate]).sum::<f64>() / 3.0
+        });
+        if centroid[2] < 1e-6 {
+            assert!(z < 0.0, "bottom face normal points inward");
+            bottom += 1;
+        } else if centroid[2] > 2.0 - 1e-6 {
+            assert!(z > 0.0, "top face normal points inward");
+            top += 1;
+        } else {
+            // The closest point on the axis is (0, 0, centroid[2])
+            assert!(
+                x.mul_add(centroid[0], y * centroid[1]) > 0.0,
+                "side face normal points inward"
+            );
+            side += 1;
+        }
+    }
+    assert!(bottom > 0 && top > 0 && side > 0);
+
+    let stl = mesh.to_stl_binary();
+    assert_eq!(stl.len(), 84 + 50 * mesh.triangles.len());
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "text")]
+use occara::geom::{Direction, Point};
+use occara::shape::Shape;
+use occara::text::{Font, TextError};
+
+#[test]
+fn test_text_geometry() {
+    let font = Font::any();
+    if !font.is_available() {
+        // Nothing to test on systems without any installed fonts
+        return;
+    }
+    let position = Point::origin().plane_axis_with(&Direction::z());
+    let text = Shape::text("CADara", &font, 10.0, &position).unwrap();
+
+    assert!(text.faces().count() >= 6);
+}
+
+#[test]
+fn test_text_missing_font() {
+    let font = Font::new("This font does not exist");
+    let position = Point::origin().plane_axis_with(&Direction::z());
+
+    assert!(matches!(
+        Shape::text("CADara", &font, 10.0, &position),
+        Err(TextError::FontNotFound(_) | TextError::NoFontAvailable)
+    ));
+}
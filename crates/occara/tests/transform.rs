@@ -0,0 +1,54 @@
+use occara::geom::{Axis, Direction, Point, Transformable, Transformation, Vector};
+use occara::measure::{distance, volume};
+use occara::shape::Shape;
+use std::f64::consts::PI;
+
+const TOLERANCE: f64 = 1e-6;
+
+fn unit_cylinder() -> Shape {
+    let axis = Point::origin().plane_axis_with(&Direction::z());
+    Shape::cylinder(&axis, 1.0, 2.0)
+}
+
+#[test]
+fn test_translate_shape() {
+    let cylinder = unit_cylinder();
+    let moved = cylinder.transform(&Transformation::translation(&Vector::new(10.0, 0.0, 0.0)));
+
+    let (value, _, point) = distance(&cylinder, &moved);
+    assert!((value - 8.0).abs() < TOLERANCE);
+    assert!((point.x() - 9.0).abs() < TOLERANCE);
+    assert!((volume(&moved) - volume(&cylinder)).abs() < TOLERANCE);
+}
+
+#[test]
+fn test_rotate_shape() {
+    let cylinder = unit_cylinder();
+    let axis = Axis::new(&Point::new(5.0, 0.0, 0.0), &Direction::z());
+    let rotated = cylinder.transform(&Transformation::rotation(&axis, PI));
+
+    let (value, _, point) = distance(&cylinder, &rotated);
+    assert!((value - 8.0).abs() < TOLERANCE);
+    assert!((point.x() - 9.0).abs() < TOLERANCE);
+}
+
+#[test]
+fn test_scale_shape() {
+    let cylinder = unit_cylinder();
+    let scaled = cylinder.transform(&Transformation::scale(&Point::origin(), 2.0));
+
+    assert!((volume(&scaled) - 8.0 * volume(&cylinder)).abs() < TOLERANCE);
+}
+
+#[test]
+fn test_chain_transformations() {
+    let cylinder = unit_cylinder();
+    let translation = Transformation::translation(&Vector::new(10.0, 0.0, 0.0));
+    let rotation = Transformation::rotation(&Axis::new(&Point::origin(), &Direction::z()), PI);
+    let moved = cylinder.transform(&translation.then(&rotation));
+
+    // Translated to x = 10 first, then rotated around the origin to x = -10
+    let (value, _, point) = distance(&cylinder, &moved);
+    assert!((value - 8.0).abs() < TOLERANCE);
+    assert!((point.x() - -9.0).abs() < TOLERANCE);
+}
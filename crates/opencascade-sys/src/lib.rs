@@ -59,7 +59,16 @@ impl OpenCascadeSource {
 
         let mut config = cmake::Config::new(source_path);
 
-        let build_dir = occt_dir.join(format!("build-{}", config.get_profile()));
+        // Builds with different optional modules must not share a build directory
+        let features = [
+            ("text", cfg!(feature = "text")),
+            ("step", cfg!(feature = "step")),
+        ]
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| format!("-{name}"))
+        .collect::<String>();
+        let build_dir = occt_dir.join(format!("build-{}{features}", config.get_profile()));
         let lib_dir = build_dir.join(LIB_DIR);
         let include_dir = build_dir.join(INCLUDE_DIR);
         let build_marker = build_dir.join(".built");
@@ -84,11 +93,8 @@ impl OpenCascadeSource {
             // More or less minimal configuration for our use case
             config
                 .define("BUILD_MODULE_Draw", "OFF")
-                .define("BUILD_MODULE_DataExchange", "OFF")
                 .define("BUILD_MODULE_ApplicationFramework", "OFF")
-                .define("BUILD_MODULE_Visualization", "OFF")
                 .define("BUILD_MODULE_DETools", "OFF")
-                .define("USE_FREETYPE", "OFF")
                 .define("USE_FREEIMAGE", "OFF")
                 .define("USE_OPENVR", "OFF")
                 .define("USE_OPENGL", "OFF")
@@ -99,6 +105,23 @@ impl OpenCascadeSource {
                 .define("USE_TBB", "OFF")
                 .define("USE_VTK", "OFF");
 
+            // Text rendering is implemented in TKService (part of the Visualization module), which needs FreeType
+            if cfg!(feature = "text") {
+                config
+                    .define("BUILD_MODULE_Visualization", "ON")
+                    .define("USE_FREETYPE", "ON");
+            } else {
+                config
+                    .define("BUILD_MODULE_Visualization", "OFF")
+                    .define("USE_FREETYPE", "OFF");
+            }
+
+            // STEP support is implemented in the DataExchange module
+            config.define(
+                "BUILD_MODULE_DataExchange",
+                if cfg!(feature = "step") { "ON" } else { "OFF" },
+            );
+
             // Set the install directories
             config
                 .define("INSTALL_DIR_LIB", LIB_DIR)
@@ -168,7 +191,14 @@ impl OpenCascadeBuild {
     pub fn link(&self) {
         println!("cargo:rustc-link-search=native={}", self.lib_dir.display());
         // I dont't know why this order works, but it does, so I'm not going to mess with it for now
-        let lib_linking_order = vec![
+        let mut lib_linking_order = vec![];
+        if cfg!(feature = "step") {
+            lib_linking_order.extend(["TKDESTEP", "TKDE", "TKXSBase"]);
+        }
+        if cfg!(feature = "text") {
+            lib_linking_order.push("TKService");
+        }
+        lib_linking_order.extend([
             "TKBO",
             "TKBool",
             "TKBRep",
@@ -186,7 +216,7 @@ impl OpenCascadeBuild {
             "TKShHealing",
             "TKTopAlgo",
             "TKernel",
-        ];
+        ]);
 
         let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap();
 
@@ -199,6 +229,11 @@ impl OpenCascadeBuild {
             }
         }
 
+        if cfg!(feature = "text") {
+            // FreeType is not built by us, link the system library
+            println!("cargo:rustc-link-lib=freetype");
+        }
+
         if target_os == "windows" {
             // Also link with the user32 library, which is for some reason needed
             println!("cargo:rustc-link-lib=user32");
@@ -0,0 +1,64 @@
+//! Compares loading a large project from JSON and from the binary format.
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use common::test_module::{TestModule, TestTransaction};
+use criterion::{criterion_group, criterion_main, Criterion};
+use project::document::transaction::TransactionArgs;
+use project::{ModuleRegistry, Project, ProjectSeed};
+use serde::de::DeserializeSeed;
+use utils::Transaction;
+
+/// Number of documents in the benchmarked project.
+///
+/// The transaction history of a document is not persisted, so the size of a saved project
+/// is determined by its documents.
+const DOCUMENTS: usize = 10_000;
+
+fn create_project() -> Project {
+    let project = Project::new("Project".to_string());
+    for i in 0..DOCUMENTS {
+        let doc_uuid = project.create_document::<TestModule>();
+        let mut doc = project.open_document::<TestModule>(doc_uuid).unwrap();
+        doc.apply(TransactionArgs::Document(TestTransaction::SetWord(
+            format!("word{i}"),
+        )))
+        .unwrap();
+    }
+    project
+}
+
+fn load_project(c: &mut Criterion) {
+    let project = create_project();
+    let json = serde_json::to_string(&project).unwrap();
+    let mut binary = vec![];
+    project.write_binary(&mut binary).unwrap();
+
+    let mut registry = ModuleRegistry::default();
+    registry.register::<TestModule>();
+
+    let mut group = c.benchmark_group("load_project");
+    group.bench_function("json", |b| {
+        b.iter(|| {
+            ProjectSeed {
+                registry: &registry,
+            }
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap()
+        });
+    });
+    group.bench_function("binary", |b| {
+        b.iter(|| {
+            ProjectSeed {
+                registry: &registry,
+            }
+            .read_binary(binary.as_slice())
+            .unwrap()
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, load_project);
+criterion_main!(benches);
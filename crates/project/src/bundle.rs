@@ -0,0 +1,189 @@
+//! Self-contained project bundles for sharing projects.
+//!
+//! A [`Bundle`] contains a serialized [`Project`] together with metadata about all modules used
+//! by its documents. This allows the recipient to find out exactly which modules are missing
+//! before attempting to open the project, and to open the remaining documents without them.
+
+use crate::{ModuleRegistry, Project, ProjectSeed};
+use serde::{de::DeserializeSeed, Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Metadata describing a module used by a project.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ModuleInfo {
+    /// The [`Uuid`] of the module, as returned by [`Module::uuid`].
+    ///
+    /// [`Module::uuid`]: crate::document::Module::uuid
+    pub uuid: Uuid,
+    /// The human-readable name of the module, as returned by [`Module::name`].
+    ///
+    /// [`Module::name`]: crate::document::Module::name
+    pub name: String,
+    /// The version of the module's data, as returned by [`Module::version`].
+    ///
+    /// Opening the documents requires at least this version of the module.
+    ///
+    /// [`Module::version`]: crate::document::Module::version
+    pub version: u32,
+}
+
+/// A project packaged together with metadata of all modules it uses.
+///
+/// Created with [`Project::export_bundle`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bundle {
+    modules: Vec<ModuleInfo>,
+    /// The project serialized to JSON.
+    ///
+    /// Stored as a string, since documents depend on the order of their fields.
+    project: String,
+}
+
+/// The error that can occur when opening a [`Bundle`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BundleError {
+    /// Some modules used by the project are not registered.
+    MissingModules(Vec<ModuleInfo>),
+    /// The project could not be serialized or deserialized.
+    Serialization(String),
+}
+
+impl std::fmt::Display for BundleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingModules(modules) => {
+                write!(f, "missing modules:")?;
+                for (i, module) in modules.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(
+                        f,
+                        "{separator}{} v{} ({})",
+                        module.name, module.version, module.uuid
+                    )?;
+                }
+                Ok(())
+            }
+            Self::Serialization(message) => write!(f, "invalid project data: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for BundleError {}
+
+impl Bundle {
+    /// Returns all modules used by the bundled project, sorted by [`Uuid`].
+    #[must_use]
+    pub fn modules(&self) -> &[ModuleInfo] {
+        &self.modules
+    }
+
+    /// Returns all modules used by the bundled project that are not registered in `registry`,
+    /// or only with a version older than [`ModuleInfo::version`].
+    #[must_use]
+    pub fn missing_modules(&self, registry: &ModuleRegistry) -> Vec<ModuleInfo> {
+        self.modules
+            .iter()
+            .filter(|module| {
+                registry
+                    .version(module.uuid)
+                    .is_none_or(|version| version < module.version)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Opens the bundled project.
+    ///
+    /// Use [`Bundle::open_partial`] to open the project even if some modules are missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BundleError::MissingModules`] listing every module that is not registered in `registry`,
+    /// or [`BundleError::Serialization`] if the project data is invalid.
+    pub fn open(&self, registry: &ModuleRegistry) -> Result<Project, BundleError> {
+        let missing = self.missing_modules(registry);
+        if !missing.is_empty() {
+            return Err(BundleError::MissingModules(missing));
+        }
+        ProjectSeed { registry }
+            .deserialize(&mut serde_json::Deserializer::from_str(&self.project))
+            .map_err(|e| BundleError::Serialization(e.to_string()))
+    }
+
+    /// Opens the bundled project without the documents of missing modules.
+    ///
+    /// See [`Bundle::missing_modules`] for which modules are considered missing.
+    ///
+    /// # Returns
+    ///
+    /// The project and the [`Uuid`]s of all documents that were left out, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BundleError::Serialization`] if the project data is invalid.
+    pub fn open_partial(
+        &self,
+        registry: &ModuleRegistry,
+    ) -> Result<(Project, Vec<Uuid>), BundleError> {
+        let missing: Vec<Uuid> = self
+            .missing_modules(registry)
+            .into_iter()
+            .map(|module| module.uuid)
+            .collect();
+        let mut project: serde_json::Value = serde_json::from_str(&self.project)
+            .map_err(|e| BundleError::Serialization(e.to_string()))?;
+
+        // Documents are stored as `{ "uuid": <module>, "model": ... }`, keyed by their own uuid.
+        // The `preserve_order` feature of `serde_json` keeps the order of their fields intact.
+        let mut skipped = vec![];
+        if let Some(documents) = project
+            .pointer_mut("/project/documents")
+            .and_then(serde_json::Value::as_object_mut)
+        {
+            documents.retain(|document, model| {
+                let module = model
+                    .get("uuid")
+                    .and_then(serde_json::Value::as_str)
+                    .and_then(|uuid| Uuid::parse_str(uuid).ok());
+                let keep = !module.is_some_and(|module| missing.contains(&module));
+                if !keep {
+                    skipped.extend(Uuid::parse_str(document).ok());
+                }
+                keep
+            });
+        }
+        skipped.sort();
+
+        let project = ProjectSeed { registry }
+            .deserialize(project)
+            .map_err(|e| BundleError::Serialization(e.to_string()))?;
+        Ok((project, skipped))
+    }
+}
+
+impl Project {
+    /// Packages this project together with metadata of all modules used by its documents.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BundleError::Serialization`] if the project could not be serialized.
+    pub fn export_bundle(&self) -> Result<Bundle, BundleError> {
+        let mut modules: Vec<ModuleInfo> = self
+            .project
+            .borrow()
+            .documents
+            .values()
+            .map(|document| ModuleInfo {
+                uuid: document.uuid,
+                name: document.model.module_name(),
+                version: document.model.module_version(),
+            })
+            .collect();
+        modules.sort();
+        modules.dedup();
+
+        let project =
+            serde_json::to_string(self).map_err(|e| BundleError::Serialization(e.to_string()))?;
+        Ok(Bundle { modules, project })
+    }
+}
@@ -12,8 +12,8 @@
 
 // Public modules and re-exports
 pub mod transaction;
-pub use module::Module;
-pub use session::{Session, Snapshot};
+pub use module::{MigrationError, Module};
+pub use session::{Session, Snapshot, TransactionRecord, TransactionRecordState};
 
 // Internal modules
 pub(crate) mod internal;
@@ -3,15 +3,23 @@ use crate::{
     transaction::{DocumentTransaction, ReversibleDocumentTransaction},
     user::User,
 };
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{self, DeserializeOwned, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    ser, Deserialize, Deserializer, Serialize, Serializer,
+};
 use std::{
     cell::RefCell,
+    cmp::Ordering,
     collections::{HashMap, VecDeque},
+    marker::PhantomData,
     rc::Weak,
 };
 use uuid::Uuid;
 
-use super::{session::internal::InternalDocumentSession, transaction::SessionApplyError, Module};
+use super::{
+    session::internal::InternalDocumentSession, transaction::SessionApplyError, MigrationError,
+    Module,
+};
 
 // TODO: write docs for these types
 /// Data required to undo and redo a transaction.
@@ -57,6 +65,24 @@ pub struct TransactionHistoryState<
     pub state: TransactionState<D, U>,
 }
 
+impl<D: ReversibleDocumentTransaction, U: ReversibleDocumentTransaction>
+    TransactionHistoryState<D, U>
+{
+    /// Returns the sub-steps of this transaction, as reported by [`DocumentTransaction::undo_steps`].
+    pub fn undo_steps(&self) -> Vec<String> {
+        match &self.state {
+            TransactionState::Applied(AppliedTransaction::Document(unit)) => {
+                D::undo_steps(&unit.args)
+            }
+            TransactionState::Applied(AppliedTransaction::User(unit)) => U::undo_steps(&unit.args),
+            TransactionState::Undone(UndoneTransaction::Document(args))
+            | TransactionState::Failed(UndoneTransaction::Document(args)) => D::undo_steps(args),
+            TransactionState::Undone(UndoneTransaction::User(args))
+            | TransactionState::Failed(UndoneTransaction::User(args)) => U::undo_steps(args),
+        }
+    }
+}
+
 // TODO: rename to InternalDcoument
 // TODO: make this more private
 /// Represents an internal model of a document within a project in `CADara`.
@@ -64,14 +90,20 @@ pub struct TransactionHistoryState<
 /// Used internally by [`Project`] to store data about a document.
 ///
 /// [`Project`]: crate::Project
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct InternalDocumentModel<M: Module> {
+    /// Version of the module that created the data, see [`Module::version`].
+    ///
+    /// Must be serialized before the data, since it is required to deserialize it.
+    pub(crate) version: u32,
     /// Document data for this document
+    #[serde(serialize_with = "serialize_versioned")]
     pub(crate) document_data: M::DocumentData,
     /// TODO: write doc
     #[serde(skip)]
     pub transaction_history: VecDeque<TransactionHistoryState<M::DocumentData, M::UserData>>,
     /// User-specific data for this document
+    #[serde(serialize_with = "serialize_versioned")]
     pub(crate) user_data: M::UserData,
     /// Shared session data for this document
     // TODO: this was an option
@@ -89,8 +121,276 @@ pub struct InternalDocumentModel<M: Module> {
     pub(crate) session_to_user: HashMap<Uuid, User>,
 }
 
+impl<'de, M: Module> Deserialize<'de> for InternalDocumentModel<M> {
+    #[allow(clippy::too_many_lines)]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "snake_case")]
+        enum Field {
+            Version,
+            DocumentData,
+            UserData,
+            ModuleUuid,
+            #[serde(other)]
+            Ignore,
+        }
+
+        struct ModelVisitor<M>(PhantomData<M>);
+
+        impl<'de, M: Module> Visitor<'de> for ModelVisitor<M> {
+            type Value = InternalDocumentModel<M>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("struct InternalDocumentModel")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let version = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let document_data = seq
+                    .next_element_seed(VersionedData::new::<M>(version, M::migrate_document_data))?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let user_data = seq
+                    .next_element_seed(VersionedData::new::<M>(version, M::migrate_user_data))?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let module_uuid = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                Ok(InternalDocumentModel::from_data(
+                    document_data,
+                    user_data,
+                    module_uuid,
+                ))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut version = None;
+                let mut document_data: Option<PendingData<M::DocumentData>> = None;
+                let mut user_data: Option<PendingData<M::UserData>> = None;
+                let mut module_uuid = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Version => {
+                            if version.is_some() {
+                                return Err(de::Error::duplicate_field("version"));
+                            }
+                            version = Some(map.next_value()?);
+                        }
+                        Field::DocumentData => {
+                            if document_data.is_some() {
+                                return Err(de::Error::duplicate_field("document_data"));
+                            }
+                            document_data = Some(match version {
+                                Some(version) => PendingData::Parsed(map.next_value_seed(
+                                    VersionedData::new::<M>(version, M::migrate_document_data),
+                                )?),
+                                None => PendingData::Buffered(map.next_value_seed(RawData)?),
+                            });
+                        }
+                        Field::UserData => {
+                            if user_data.is_some() {
+                                return Err(de::Error::duplicate_field("user_data"));
+                            }
+                            user_data = Some(match version {
+                                Some(version) => PendingData::Parsed(map.next_value_seed(
+                                    VersionedData::new::<M>(version, M::migrate_user_data),
+                                )?),
+                                None => PendingData::Buffered(map.next_value_seed(RawData)?),
+                            });
+                        }
+                        Field::ModuleUuid => {
+                            if module_uuid.is_some() {
+                                return Err(de::Error::duplicate_field("module_uuid"));
+                            }
+                            module_uuid = Some(map.next_value()?);
+                        }
+                        Field::Ignore => {
+                            let _: de::IgnoredAny = map.next_value()?;
+                        }
+                    }
+                }
+                // Projects saved before versioning was introduced have no version
+                let version = version.unwrap_or(0);
+                let document_data = document_data
+                    .ok_or_else(|| de::Error::missing_field("document_data"))?
+                    .resolve(VersionedData::new::<M>(version, M::migrate_document_data))
+                    .map_err(de::Error::custom)?;
+                let user_data = user_data
+                    .ok_or_else(|| de::Error::missing_field("user_data"))?
+                    .resolve(VersionedData::new::<M>(version, M::migrate_user_data))
+                    .map_err(de::Error::custom)?;
+                Ok(InternalDocumentModel::from_data(
+                    document_data,
+                    user_data,
+                    module_uuid.ok_or_else(|| de::Error::missing_field("module_uuid"))?,
+                ))
+            }
+        }
+
+        const FIELDS: &[&str] = &["version", "document_data", "user_data", "module_uuid"];
+        deserializer.deserialize_struct("InternalDocumentModel", FIELDS, ModelVisitor(PhantomData))
+    }
+}
+
+/// Serializes module data, so that it can be migrated by [`VersionedData`].
+///
+/// Migrations operate on a [`serde_json::Value`], which can only be deserialized from
+/// self-describing formats. For other formats, like the binary project format, the data is
+/// therefore stored as an embedded JSON blob.
+fn serialize_versioned<T: Serialize, S: Serializer>(
+    data: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        data.serialize(serializer)
+    } else {
+        let json = serde_json::to_vec(data).map_err(ser::Error::custom)?;
+        serializer.serialize_bytes(&json)
+    }
+}
+
+/// Deserializes data saved with the given [`Module::version`], migrating it if necessary.
+struct VersionedData<T> {
+    version: u32,
+    current_version: u32,
+    migrate: fn(u32, serde_json::Value) -> Result<T, MigrationError>,
+}
+
+impl<T> VersionedData<T> {
+    fn new<M: Module>(
+        version: u32,
+        migrate: fn(u32, serde_json::Value) -> Result<T, MigrationError>,
+    ) -> Self {
+        Self {
+            version,
+            current_version: M::version(),
+            migrate,
+        }
+    }
+}
+
+impl<T> VersionedData<T> {
+    fn deserialize_from<'de, D>(self, deserializer: D) -> Result<T, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        match self.version.cmp(&self.current_version) {
+            Ordering::Equal => T::deserialize(deserializer),
+            Ordering::Less => {
+                let data = serde_json::Value::deserialize(deserializer)?;
+                (self.migrate)(self.version, data).map_err(de::Error::custom)
+            }
+            Ordering::Greater => Err(de::Error::custom(format!(
+                "data was saved with version {}, but only versions up to {} are supported",
+                self.version, self.current_version
+            ))),
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned> DeserializeSeed<'de> for VersionedData<T> {
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            self.deserialize_from(deserializer)
+        } else {
+            // See `serialize_versioned`
+            let json = deserializer.deserialize_byte_buf(ByteBufVisitor)?;
+            self.deserialize_from(&mut serde_json::Deserializer::from_slice(&json))
+                .map_err(de::Error::custom)
+        }
+    }
+}
+
+/// Module data read before the version it was saved with is known.
+enum PendingData<T> {
+    Parsed(T),
+    /// The data as written by [`serialize_versioned`], deserialized once the version is known.
+    Buffered(serde_json::Value),
+}
+
+impl<T: DeserializeOwned> PendingData<T> {
+    fn resolve(self, seed: VersionedData<T>) -> Result<T, serde_json::Error> {
+        match self {
+            Self::Parsed(data) => Ok(data),
+            Self::Buffered(data) => seed.deserialize_from(data),
+        }
+    }
+}
+
+/// Deserializes module data written by [`serialize_versioned`] without interpreting it.
+struct RawData;
+
+impl<'de> DeserializeSeed<'de> for RawData {
+    type Value = serde_json::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            serde_json::Value::deserialize(deserializer)
+        } else {
+            let json = deserializer.deserialize_byte_buf(ByteBufVisitor)?;
+            serde_json::from_slice(&json).map_err(de::Error::custom)
+        }
+    }
+}
+
+/// Deserializes a byte buffer written with [`Serializer::serialize_bytes`].
+struct ByteBufVisitor;
+
+impl Visitor<'_> for ByteBufVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a byte buffer")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+}
+
 // TODO: make methods private, write docs
 impl<M: Module> InternalDocumentModel<M> {
+    /// Creates a document model from persistent data, without any open sessions.
+    pub(crate) fn from_data(
+        document_data: M::DocumentData,
+        user_data: M::UserData,
+        module_uuid: Uuid,
+    ) -> Self {
+        Self {
+            version: M::version(),
+            document_data,
+            transaction_history: VecDeque::new(),
+            user_data,
+            shared_data: None,
+            sessions: vec![],
+            module_uuid,
+            session_to_user: HashMap::new(),
+        }
+    }
+
     pub fn apply_document(
         &mut self,
         args: <M::DocumentData as DocumentTransaction>::Args,
@@ -1,7 +1,7 @@
 //! Module with the [`Module`] trait.
 use crate::transaction::{DocumentTransaction, ReversibleDocumentTransaction};
 use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 use uuid::Uuid;
 
 /// Modules are the main building blocks of a document in `CADara`.
@@ -64,4 +64,78 @@ pub trait Module: Clone + Default + Debug + 'static {
     /// The [`Uuid`] associated with the module.
     /// Must be unique for each module.
     fn uuid() -> Uuid;
+
+    /// Returns the [`Uuid`]s of all modules this module depends on.
+    ///
+    /// A [`ModuleRegistry`] is only usable if all dependencies of its modules are registered too,
+    /// see [`ModuleRegistry::check_dependencies`]. Defaults to no dependencies.
+    ///
+    /// [`ModuleRegistry`]: crate::ModuleRegistry
+    /// [`ModuleRegistry::check_dependencies`]: crate::ModuleRegistry::check_dependencies
+    #[must_use]
+    fn dependencies() -> Vec<Uuid> {
+        vec![]
+    }
+
+    /// Returns the version of the layout of [`Module::DocumentData`] and [`Module::UserData`].
+    ///
+    /// Increase this whenever the persistent data changes in a way that older projects can no longer
+    /// be deserialized, and implement [`Module::migrate_document_data`] and [`Module::migrate_user_data`]
+    /// to convert data saved by older versions. Defaults to `0`, which is also assumed for projects saved
+    /// before versioning was introduced.
+    #[must_use]
+    fn version() -> u32 {
+        0
+    }
+
+    /// Converts document data saved by an older version of this module.
+    ///
+    /// Called when deserializing a project saved with a version lower than [`Module::version`].
+    /// The data is passed as a JSON value, for both JSON and binary projects.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MigrationError`] if the data can not be converted. By default, every version is rejected.
+    fn migrate_document_data(
+        version: u32,
+        _data: serde_json::Value,
+    ) -> Result<Self::DocumentData, MigrationError> {
+        Err(MigrationError::UnsupportedVersion(version))
+    }
+
+    /// Converts user data saved by an older version of this module.
+    ///
+    /// See [`Module::migrate_document_data`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MigrationError`] if the data can not be converted. By default, every version is rejected.
+    fn migrate_user_data(
+        version: u32,
+        _data: serde_json::Value,
+    ) -> Result<Self::UserData, MigrationError> {
+        Err(MigrationError::UnsupportedVersion(version))
+    }
 }
+
+/// The error returned when data of an older [`Module::version`] can not be migrated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MigrationError {
+    /// Data saved with this version can not be migrated.
+    UnsupportedVersion(u32),
+    /// The data does not match the layout of its version.
+    InvalidData(String),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => {
+                write!(f, "migration from version {version} is not supported")
+            }
+            Self::InvalidData(message) => write!(f, "invalid data: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
@@ -2,17 +2,46 @@ pub mod internal;
 
 use super::{
     internal::{
-        AppliedTransaction, InternalDocumentModel, TransactionState, UndoUnit, UndoneTransaction,
+        AppliedTransaction, InternalDocumentModel, TransactionHistoryState, TransactionState,
+        UndoUnit, UndoneTransaction,
     },
     transaction, Module,
 };
 use crate::transaction::{DocumentTransaction, ReversibleDocumentTransaction};
+use crate::user::User;
 use internal::InternalDocumentSession;
 use std::{
     cell::RefCell,
     rc::{Rc, Weak},
 };
 use utils::Transaction;
+use uuid::Uuid;
+
+/// An entry in the transaction history of a document.
+///
+/// Retrieved using [`Session::transaction_log`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TransactionRecord {
+    /// The name of the transaction, as returned by [`DocumentTransaction::undo_history_name`].
+    pub name: String,
+    /// The unique identifier of the session that applied the transaction.
+    pub session: Uuid,
+    /// The user that applied the transaction.
+    pub user: User,
+    /// Whether the transaction currently affects the document.
+    pub state: TransactionRecordState,
+}
+
+/// The state of a transaction in the transaction history, see [`TransactionRecord`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransactionRecordState {
+    /// The transaction is applied to the document.
+    Applied,
+    /// The transaction was undone and can be redone.
+    Undone,
+    /// The transaction could not be reapplied after an earlier transaction was undone.
+    Failed,
+}
 
 /// Represents a snapshot of a document's state in a session.
 ///
@@ -71,6 +100,8 @@ impl<M: Module> Session<M> {
     }
 
     // TODO: add doc
+    // Data is stored behind a RefCell, but this should still require exclusive access
+    #[allow(clippy::needless_pass_by_ref_mut)]
     fn apply_session(
         &mut self,
         args: <M::SessionData as DocumentTransaction>::Args,
@@ -114,6 +145,58 @@ impl<M: Module> Session<M> {
     /// This function is not expected to panic under normal circumstances.
     #[must_use]
     pub fn undo_redo_list(&self) -> (Vec<String>, usize) {
+        self.undo_redo_entries(|history_state| history_state.name.clone())
+    }
+
+    /// Retrieves the steps of all transactions along with the position of the next transaction to be redone.
+    ///
+    /// This is a detailed version of [`Session::undo_redo_list`]. Instead of a single name, each transaction
+    /// is described by its individual steps, as returned by [`DocumentTransaction::undo_steps`].
+    /// The returned index has the same meaning as in [`Session::undo_redo_list`].
+    ///
+    /// # Panics
+    ///
+    /// This function is not expected to panic under normal circumstances.
+    #[must_use]
+    pub fn undo_redo_steps(&self) -> (Vec<Vec<String>>, usize) {
+        self.undo_redo_entries(TransactionHistoryState::undo_steps)
+    }
+
+    /// Retrieves all transactions applied to the persistent data of this document, from all sessions.
+    ///
+    /// In contrast to [`Session::undo_redo_list`], which only lists transactions of this session,
+    /// this returns the history of the whole document in the order the transactions were applied.
+    /// This includes transactions that are currently undone. Transactions that were rejected when first
+    /// applied are not recorded.
+    ///
+    /// # Panics
+    ///
+    /// This function is not expected to panic under normal circumstances.
+    #[must_use]
+    pub fn transaction_log(&self) -> Vec<TransactionRecord> {
+        let ref_cell = self.document_model_ref.upgrade().unwrap();
+        let internal_doc = ref_cell.borrow();
+        internal_doc
+            .transaction_history
+            .iter()
+            .map(|history_state| TransactionRecord {
+                name: history_state.name.clone(),
+                session: history_state.session,
+                user: internal_doc.session_to_user[&history_state.session],
+                state: match history_state.state {
+                    TransactionState::Applied(_) => TransactionRecordState::Applied,
+                    TransactionState::Undone(_) => TransactionRecordState::Undone,
+                    TransactionState::Failed(_) => TransactionRecordState::Failed,
+                },
+            })
+            .collect()
+    }
+
+    /// Collects an entry for each transaction of this session, see [`Session::undo_redo_list`].
+    fn undo_redo_entries<T>(
+        &self,
+        entry: impl Fn(&TransactionHistoryState<M::DocumentData, M::UserData>) -> T,
+    ) -> (Vec<T>, usize) {
         let session_uuid = self.session.borrow().session_uuid;
         let ref_cell = self.document_model_ref.upgrade().unwrap();
         let internal_doc = ref_cell.borrow();
@@ -127,7 +210,7 @@ impl<M: Module> Session<M> {
             if history_state.session != session_uuid {
                 continue;
             }
-            undo_list.push(history_state.name.clone());
+            undo_list.push(entry(history_state));
             match &history_state.state {
                 // We hide failed transactions from the user, since current the user can't do anything about them
                 TransactionState::Applied(_) | TransactionState::Failed(_) => {
@@ -278,7 +361,7 @@ impl<M: Module> Session<M> {
                             queued: UndoData::WasFailed,
                             should_redo: true,
                         });
-                    };
+                    }
                 }
                 TransactionState::Applied(transaction) => {
                     if history_state.session == session_uuid {
@@ -758,9 +841,8 @@ impl<M: Module> Session<M> {
                 // If true, we want to redo it
                 let is_user_requested = is_current_session && redo_left > 0;
                 // If true, it was marked for redo
-                let marked_for_recompute = action.map_or(false, |action| {
-                    matches!(action.action_type, ActionType::Recompute)
-                });
+                let marked_for_recompute = action
+                    .is_some_and(|action| matches!(action.action_type, ActionType::Recompute));
 
                 if is_user_requested {
                     // This is a transaction, that the user requested to redo
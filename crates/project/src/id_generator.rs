@@ -0,0 +1,64 @@
+//! Generation of unique identifiers for new documents.
+//!
+//! By default, a [`Project`] uses random [`Uuid`]s. Tests producing snapshots of serialized
+//! projects can use a [`SeededIdGenerator`] instead, to get the same identifiers on every run.
+//!
+//! [`Project`]: crate::Project
+
+use std::fmt::Debug;
+use uuid::Uuid;
+
+/// A source of identifiers for newly created documents.
+///
+/// Set with [`Project::with_id_generator`].
+///
+/// [`Project::with_id_generator`]: crate::Project::with_id_generator
+pub trait IdGenerator: Debug {
+    /// Returns a new identifier, which must not have been returned before.
+    fn generate(&mut self) -> Uuid;
+}
+
+/// Generates random version 4 [`Uuid`]s, used by default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RandomIdGenerator;
+
+impl IdGenerator for RandomIdGenerator {
+    fn generate(&mut self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// Generates a reproducible sequence of version 4 [`Uuid`]s from a seed.
+///
+/// Intended for tests only, since identifiers are predictable and will collide
+/// between projects created with the same seed.
+#[derive(Clone, Copy, Debug)]
+pub struct SeededIdGenerator {
+    state: u64,
+}
+
+impl SeededIdGenerator {
+    /// Creates a new generator, two generators with the same seed produce the same identifiers.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Advances the state using `SplitMix64`.
+    const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl IdGenerator for SeededIdGenerator {
+    fn generate(&mut self) -> Uuid {
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&self.next_u64().to_le_bytes());
+        bytes[8..].copy_from_slice(&self.next_u64().to_le_bytes());
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
+}
@@ -16,20 +16,25 @@
 // TODO: Transactions should be split into a normal and +unchecked version
 
 // Public modules
+pub mod bundle;
 pub mod document;
+pub mod id_generator;
 pub mod manager;
 pub mod transaction;
 pub mod user;
 
+use bincode::Options;
 use document::{
     internal::InternalDocumentModel, session::internal::InternalDocumentSession, Module, Session,
 };
+use id_generator::{IdGenerator, RandomIdGenerator};
 use serde::de::{DeserializeSeed, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::any::Any;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
+use std::io::{self, Read, Write};
 use std::marker::PhantomData;
 use std::path::PathBuf;
 use std::rc::Rc;
@@ -45,6 +50,12 @@ trait DocumentModelTrait: erased_serde::Serialize + Debug {
     /// Retrieves a mutable reference to the underlying type as a trait object.
     /// This is used for downcasting to the concrete `SharedDocumentModel` type.
     fn as_any(&mut self) -> &mut dyn Any;
+    /// Returns the human-readable name of the module implementing this document.
+    fn module_name(&self) -> String;
+    /// Returns the data version of the module implementing this document, see [`Module::version`].
+    fn module_version(&self) -> u32;
+    /// Returns `true` if at least one session of this document is open.
+    fn has_open_sessions(&self) -> bool;
 }
 erased_serde::serialize_trait_object!(DocumentModelTrait);
 
@@ -82,6 +93,18 @@ impl<M: Module> DocumentModelTrait for SharedDocumentModel<M> {
     fn as_any(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn module_name(&self) -> String {
+        M::name()
+    }
+
+    fn module_version(&self) -> u32 {
+        M::version()
+    }
+
+    fn has_open_sessions(&self) -> bool {
+        !self.0.borrow().sessions.is_empty()
+    }
 }
 
 impl<M: Module> Serialize for SharedDocumentModel<M> {
@@ -117,7 +140,78 @@ impl<'de> Deserialize<'de> for ErasedDocumentModel {
 /// A registry containing all installed modules necessary for deserialization.
 #[derive(Clone, Debug, Default)]
 pub struct ModuleRegistry {
-    modules: HashMap<Uuid, BoxedDeserializeFunction<Box<dyn DocumentModelTrait>>>,
+    modules: HashMap<Uuid, RegisteredModule>,
+}
+
+/// A module registered in a [`ModuleRegistry`].
+#[derive(Clone, Debug)]
+struct RegisteredModule {
+    /// See [`Module::name`].
+    name: String,
+    /// See [`Module::version`].
+    version: u32,
+    deserialize: BoxedDeserializeFunction<Box<dyn DocumentModelTrait>>,
+    /// See [`Module::dependencies`].
+    dependencies: Vec<Uuid>,
+}
+
+/// The error returned by [`ModuleRegistry::check_dependencies`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistryError {
+    /// The module `module` depends on `dependency`, which is not registered.
+    MissingDependency { module: Uuid, dependency: Uuid },
+}
+
+impl std::fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingDependency { module, dependency } => write!(
+                f,
+                "module {module} depends on module {dependency}, which is not registered"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// The error returned by [`ProjectSeed::read_json`] and [`ProjectSeed::read_binary`].
+#[derive(Debug)]
+pub enum LoadError {
+    /// The registry is misconfigured, see [`ModuleRegistry::check_dependencies`].
+    Registry(RegistryError),
+    /// Reading failed or the data is invalid.
+    Io(io::Error),
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Registry(e) => write!(f, "invalid module registry: {e}"),
+            Self::Io(e) => write!(f, "failed to read project: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Registry(e) => Some(e),
+            Self::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<RegistryError> for LoadError {
+    fn from(e: RegistryError) -> Self {
+        Self::Registry(e)
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
 }
 
 impl ModuleRegistry {
@@ -125,11 +219,68 @@ impl ModuleRegistry {
     where
         M: Module + for<'de> Deserialize<'de>,
     {
-        self.modules.insert(M::uuid(), |d| {
-            Ok(Box::new(
-                erased_serde::deserialize::<SharedDocumentModel<M>>(d)?,
-            ))
-        });
+        self.modules.insert(
+            M::uuid(),
+            RegisteredModule {
+                name: M::name(),
+                version: M::version(),
+                deserialize: |d| {
+                    Ok(Box::new(
+                        erased_serde::deserialize::<SharedDocumentModel<M>>(d)?,
+                    ))
+                },
+                dependencies: M::dependencies(),
+            },
+        );
+    }
+
+    /// Returns the [`Uuid`] and name of each registered module, sorted by [`Uuid`].
+    #[must_use]
+    pub fn registered_modules(&self) -> Vec<(Uuid, String)> {
+        let mut modules: Vec<_> = self
+            .modules
+            .iter()
+            .map(|(uuid, module)| (*uuid, module.name.clone()))
+            .collect();
+        modules.sort();
+        modules
+    }
+
+    /// Returns `true` if the module with the given [`Uuid`] is registered.
+    #[must_use]
+    pub fn contains(&self, module: Uuid) -> bool {
+        self.modules.contains_key(&module)
+    }
+
+    /// Returns the [`Module::version`] of the module with the given [`Uuid`], if it is registered.
+    #[must_use]
+    pub fn version(&self, module: Uuid) -> Option<u32> {
+        self.modules.get(&module).map(|module| module.version)
+    }
+
+    /// Checks that all dependencies of the registered modules are registered too.
+    ///
+    /// This is also checked before deserializing a project with [`ProjectSeed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::MissingDependency`] for the first missing dependency, ordered by module [`Uuid`].
+    pub fn check_dependencies(&self) -> Result<(), RegistryError> {
+        let mut modules: Vec<_> = self.modules.iter().collect();
+        modules.sort_by_key(|(uuid, _)| **uuid);
+        for (module, registered) in modules {
+            if let Some(dependency) = registered
+                .dependencies
+                .iter()
+                .find(|dependency| !self.contains(**dependency))
+            {
+                return Err(RegistryError::MissingDependency {
+                    module: *module,
+                    dependency: *dependency,
+                });
+            }
+        }
+        Ok(())
     }
 }
 
@@ -147,10 +298,18 @@ where
 {
     type Value = Project;
 
+    /// Deserializes a project with the modules of the registry.
+    ///
+    /// A misconfigured registry is only reported as a generic deserialization error, use
+    /// [`ProjectSeed::read_json`] or [`ProjectSeed::read_binary`] to get a [`RegistryError`].
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
+        self.registry
+            .check_dependencies()
+            .map_err(serde::de::Error::custom)?;
+
         // Put the registry in thread local storage
         MODULE_REGISTRY.with(|r| {
             *r.borrow_mut() = Some(self.registry);
@@ -166,6 +325,56 @@ where
     }
 }
 
+impl ProjectSeed<'_> {
+    /// Reads a project serialized as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadError::Registry`] if the registry is misconfigured, or [`LoadError::Io`] if
+    /// reading fails, the data is invalid or a module used by the project is not registered.
+    pub fn read_json<R: Read>(self, reader: R) -> Result<Project, LoadError> {
+        self.registry.check_dependencies()?;
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        Ok(self
+            .deserialize(&mut deserializer)
+            .map_err(io::Error::from)?)
+    }
+
+    /// Reads a project written with [`Project::write_binary`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LoadError::Registry`] if the registry is misconfigured, or [`LoadError::Io`] if
+    /// reading fails, the data is invalid or larger than [`MAX_BINARY_SIZE`], or a module used
+    /// by the project is not registered.
+    pub fn read_binary<R: Read>(self, reader: R) -> Result<Project, LoadError> {
+        self.registry.check_dependencies()?;
+        let mut deserializer = bincode::Deserializer::with_reader(reader, binary_options());
+        Ok(self
+            .deserialize(&mut deserializer)
+            .map_err(|e| binary_error(*e, io::ErrorKind::InvalidData))?)
+    }
+}
+
+/// The maximum size of a project in the binary format, in bytes.
+///
+/// Reading stops with an error once this size is exceeded, so a corrupted length prefix can not
+/// cause an unbounded allocation.
+pub const MAX_BINARY_SIZE: u64 = 1 << 30;
+
+/// The `bincode` options used by [`Project::write_binary`] and [`ProjectSeed::read_binary`].
+fn binary_options() -> impl Options {
+    bincode::options().with_limit(MAX_BINARY_SIZE)
+}
+
+/// Converts a `bincode` error to an [`io::Error`], keeping the original error of failed I/O operations.
+fn binary_error(error: bincode::ErrorKind, kind: io::ErrorKind) -> io::Error {
+    match error {
+        bincode::ErrorKind::Io(e) => e,
+        e => io::Error::new(kind, e),
+    }
+}
+
 type BoxedDeserializeFunction<O> =
     for<'de> fn(&mut dyn erased_serde::Deserializer<'de>) -> Result<O, erased_serde::Error>;
 
@@ -199,7 +408,7 @@ where
 
         struct FieldVisitor;
 
-        impl<'de> Visitor<'de> for FieldVisitor {
+        impl Visitor<'_> for FieldVisitor {
             type Value = ModuleField;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -264,21 +473,22 @@ where
             }
 
             #[inline]
-            fn visit_seq<V>(self, mut _seq: V) -> Result<ErasedDocumentModel, V::Error>
+            fn visit_seq<V>(self, mut seq: V) -> Result<ErasedDocumentModel, V::Error>
             where
                 V: serde::de::SeqAccess<'de>,
             {
-                // let uuid = seq
-                //     .next_element()?
-                //     .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
-                // let model = seq
-                //     // .next_element_seed(ModuleSeed {
-                //     //     registry: self.registry,
-                //     // })?
-                //     .next_element()?
-                //     .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
-                // Ok(ErasedDocumentModel { uuid, model })
-                todo!("sequential deserialization of ErasedDocumentModel is not supported yet")
+                let uuid: Uuid = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                let d = self
+                    .registry
+                    .modules
+                    .get(&uuid)
+                    .ok_or_else(|| serde::de::Error::custom("module not found in registry"))?;
+                let model = seq
+                    .next_element_seed(BoxedDeserializerSeed(d.deserialize))?
+                    .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                Ok(ErasedDocumentModel { uuid, model })
             }
 
             #[inline]
@@ -307,7 +517,8 @@ where
                                 serde::de::Error::custom("module not found in registry")
                             })?;
 
-                            model = Some(map.next_value_seed(BoxedDeserializerSeed(*d))?);
+                            model =
+                                Some(map.next_value_seed(BoxedDeserializerSeed(d.deserialize))?);
                         }
                         ModuleField::Ignore => {
                             let _: serde::de::IgnoredAny = map.next_value()?;
@@ -344,7 +555,9 @@ where
 #[derive(Serialize, Deserialize, Debug)]
 struct InternalProject {
     /// A map linking document UUIDs to their corresponding type-erased document models.
-    documents: HashMap<Uuid, ErasedDocumentModel>,
+    ///
+    /// Ordered, so that serializing the same project always produces the same output.
+    documents: BTreeMap<Uuid, ErasedDocumentModel>,
     /// The name of the project.
     name: String,
     /// A list of tags associated with the project for categorization or searchability.
@@ -353,6 +566,76 @@ struct InternalProject {
     // TODO: implement this
     #[serde(skip)]
     _path: Option<PathBuf>,
+    /// The generator used for the UUIDs of new documents.
+    #[serde(skip, default = "default_id_generator")]
+    id_generator: Box<dyn IdGenerator>,
+}
+
+fn default_id_generator() -> Box<dyn IdGenerator> {
+    Box::new(RandomIdGenerator)
+}
+
+/// The error returned by [`Project::serializable`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SerializeError {
+    /// The documents with these [`Uuid`]s have open sessions, whose session and shared data would not be serialized.
+    OpenSessions(Vec<Uuid>),
+}
+
+impl std::fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OpenSessions(documents) => {
+                write!(f, "documents with open sessions:")?;
+                for (i, document) in documents.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(f, "{separator}{document}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SerializeError {}
+
+/// The error returned by [`Project::try_open_document`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OpenDocumentError {
+    /// The project contains no document with this [`Uuid`].
+    NotFound(Uuid),
+    /// The document is implemented by a different module than requested.
+    ModuleMismatch {
+        /// The [`Uuid`] of the requested module.
+        expected: Uuid,
+        /// The [`Uuid`] of the module implementing the document.
+        actual: Uuid,
+    },
+}
+
+impl std::fmt::Display for OpenDocumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(document) => write!(f, "document {document} not found"),
+            Self::ModuleMismatch { expected, actual } => write!(
+                f,
+                "document is implemented by module {actual}, but module {expected} was requested"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OpenDocumentError {}
+
+/// Information about a document in a [`Project`], returned by [`Project::iter_documents`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentInfo {
+    /// The [`Uuid`] of the document, used to open it with [`Project::open_document`].
+    pub uuid: Uuid,
+    /// The [`Uuid`] of the module implementing the document.
+    pub module: Uuid,
+    /// Whether the document currently has open sessions.
+    pub has_open_sessions: bool,
 }
 
 /// Represents a project within the `CADara` application.
@@ -382,10 +665,11 @@ impl Project {
     pub fn new(name: String) -> Self {
         Self {
             project: Rc::new(RefCell::new(InternalProject {
-                documents: HashMap::new(),
+                documents: BTreeMap::new(),
                 name,
                 tags: vec![],
                 _path: None,
+                id_generator: default_id_generator(),
             })),
             user: User::local(),
         }
@@ -397,17 +681,99 @@ impl Project {
     pub fn new_with_path(name: String, user: User, path: PathBuf) -> Self {
         Self {
             project: Rc::new(RefCell::new(InternalProject {
-                documents: HashMap::new(),
+                documents: BTreeMap::new(),
                 name,
                 tags: vec![],
                 _path: Some(path),
+                id_generator: default_id_generator(),
             })),
             user,
         }
     }
 
+    /// Checks that serializing this project will not discard any data.
+    ///
+    /// [`Project`] implements [`Serialize`], but only persistent data is serialized. Session data,
+    /// shared data and the undo history of open sessions are silently dropped. Use this as a guard
+    /// before serializing to make sure all sessions were closed.
+    ///
+    /// The transaction history of documents without open sessions is not serialized either, but
+    /// is not reported: transactions can only be undone by the session that applied them, so the
+    /// history of closed sessions is only visible through [`Session::transaction_log`].
+    ///
+    /// [`Session::transaction_log`]: crate::document::Session::transaction_log
+    ///
+    /// # Returns
+    ///
+    /// This project, so that the call can be chained with the serializer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerializeError::OpenSessions`] listing all documents that still have open sessions.
+    pub fn serializable(&self) -> Result<&Self, SerializeError> {
+        let project = self.project.borrow();
+        let mut open: Vec<Uuid> = project
+            .documents
+            .iter()
+            .filter(|(_, document)| document.model.has_open_sessions())
+            .map(|(uuid, _)| *uuid)
+            .collect();
+        if open.is_empty() {
+            Ok(self)
+        } else {
+            open.sort();
+            Err(SerializeError::OpenSessions(open))
+        }
+    }
+
+    /// Writes the project in a compact binary format.
+    ///
+    /// This is faster and smaller than the JSON representation, but not human-readable.
+    /// Like serializing with serde, only persistent data is written, see [`Project::serializable`].
+    /// Use [`ProjectSeed::read_binary`] to read the project back.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails or the project is larger than [`MAX_BINARY_SIZE`].
+    pub fn write_binary<W: Write>(&self, writer: W) -> io::Result<()> {
+        binary_options()
+            .serialize_into(writer, self)
+            .map_err(|e| binary_error(*e, io::ErrorKind::Other))
+    }
+
+    /// Replaces the generator used for the UUIDs of new documents.
+    ///
+    /// Projects use random UUIDs by default. Tests can use a [`SeededIdGenerator`] to create
+    /// projects that serialize identically on every run.
+    ///
+    /// [`SeededIdGenerator`]: id_generator::SeededIdGenerator
+    #[must_use]
+    pub fn with_id_generator(self, generator: impl IdGenerator + 'static) -> Self {
+        self.project.borrow_mut().id_generator = Box::new(generator);
+        self
+    }
+
+    /// Returns information about all documents in this project, ordered by their [`Uuid`].
+    // Collecting is required, since the iterator can not hold the borrow of the project
+    #[allow(clippy::needless_collect)]
+    pub fn iter_documents(&self) -> impl Iterator<Item = DocumentInfo> {
+        let project = self.project.borrow();
+        let documents: Vec<_> = project
+            .documents
+            .iter()
+            .map(|(uuid, document)| DocumentInfo {
+                uuid: *uuid,
+                module: document.uuid,
+                has_open_sessions: document.model.has_open_sessions(),
+            })
+            .collect();
+        documents.into_iter()
+    }
+
     /// Opens a session for a document in this project.
     ///
+    /// Use [`Project::try_open_document`] to find out why a document could not be opened.
+    ///
     /// # Arguments
     ///
     /// * `document_uuid` - The unique identifier of the document to open.
@@ -417,24 +783,46 @@ impl Project {
     /// An `Option` containing a `Session` if the document could be opened, or `None` otherwise.
     #[must_use]
     pub fn open_document<M: Module>(&self, document_uuid: Uuid) -> Option<Session<M>> {
-        // TODO: Option -> Result
+        self.try_open_document(document_uuid).ok()
+    }
+
+    /// Opens a session for a document in this project.
+    ///
+    /// # Arguments
+    ///
+    /// * `document_uuid` - The unique identifier of the document to open.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OpenDocumentError::NotFound`] if the project has no document with this [`Uuid`],
+    /// or [`OpenDocumentError::ModuleMismatch`] if the document is implemented by a module other than `M`.
+    pub fn try_open_document<M: Module>(
+        &self,
+        document_uuid: Uuid,
+    ) -> Result<Session<M>, OpenDocumentError> {
         let project = &self.project;
 
         // first, we get the document model from the project (if it exists)
         let mut mut_project = project.borrow_mut();
-        let boxed_proj_doc = mut_project
+        let erased_model = mut_project
             .documents
-            .get_mut(&document_uuid)?
+            .get_mut(&document_uuid)
+            .ok_or(OpenDocumentError::NotFound(document_uuid))?;
+        let module_uuid = erased_model.uuid;
+        let document_model: &mut SharedDocumentModel<M> = erased_model
             .model
             .as_mut()
-            .as_any();
-        let document_model: &mut SharedDocumentModel<M> =
-            boxed_proj_doc.downcast_mut::<SharedDocumentModel<M>>()?;
+            .as_any()
+            .downcast_mut::<SharedDocumentModel<M>>()
+            .ok_or_else(|| OpenDocumentError::ModuleMismatch {
+                expected: M::uuid(),
+                actual: module_uuid,
+            })?;
 
         // Create a new session for the document
         let session =
             InternalDocumentSession::new(document_model, project, document_uuid, self.user);
-        Some(Session {
+        Ok(Session {
             session,
             document_model_ref: Rc::downgrade(&document_model.0),
         })
@@ -447,18 +835,14 @@ impl Project {
     /// The unique identifier [`Uuid`] of the newly created document.
     #[must_use]
     pub fn create_document<M: Module>(&self) -> Uuid {
-        let new_doc_uuid = Uuid::new_v4();
-
         let mut project = self.project.borrow_mut();
-        let proj_doc = InternalDocumentModel::<M> {
-            document_data: M::DocumentData::default(),
-            user_data: M::UserData::default(),
-            sessions: vec![],
-            module_uuid: M::uuid(),
-            shared_data: None,
-            transaction_history: std::collections::VecDeque::new(),
-            session_to_user: HashMap::new(),
-        };
+        let new_doc_uuid = project.id_generator.generate();
+
+        let proj_doc = InternalDocumentModel::<M>::from_data(
+            M::DocumentData::default(),
+            M::UserData::default(),
+            M::uuid(),
+        );
         let doc_model: SharedDocumentModel<M> =
             SharedDocumentModel(Rc::new(RefCell::new(proj_doc)));
         project.documents.insert(
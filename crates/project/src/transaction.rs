@@ -63,6 +63,15 @@ pub trait DocumentTransaction {
     /// # Returns
     /// The name of the transaction, should be a short string, ideally max 20 characters.
     fn undo_history_name(args: &Self::Args) -> String;
+
+    /// Returns the individual steps of the transaction for a detailed undo history.
+    ///
+    /// Transactions bundling several edits can override this to describe each of them separately,
+    /// for example "Move point x" and "Move point y". By default, the transaction consists of a single
+    /// step named by [`DocumentTransaction::undo_history_name`].
+    fn undo_steps(args: &Self::Args) -> Vec<String> {
+        vec![Self::undo_history_name(args)]
+    }
 }
 
 /// A trait for transactions that can be reversed.
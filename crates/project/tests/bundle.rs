@@ -0,0 +1,171 @@
+mod common;
+use common::minimal_test_module::{self, *};
+use common::test_module::*;
+use document::Module;
+use project::bundle::{BundleError, ModuleInfo};
+use project::document::transaction::TransactionArgs;
+use project::*;
+use serde::Deserialize;
+use utils::Transaction;
+use uuid::Uuid;
+
+/// A newer version of [`MinimalTestModule`], whose data can not be read by the older version.
+#[derive(Clone, Default, Debug, PartialEq, Deserialize)]
+struct MinimalTestModuleV1 {}
+
+impl Module for MinimalTestModuleV1 {
+    type DocumentData = minimal_test_module::TestDataSection;
+    type UserData = minimal_test_module::TestDataSection;
+    type SessionData = minimal_test_module::TestDataSection;
+    type SharedData = minimal_test_module::TestDataSection;
+
+    fn name() -> String {
+        MinimalTestModule::name()
+    }
+    fn uuid() -> Uuid {
+        MinimalTestModule::uuid()
+    }
+    fn version() -> u32 {
+        1
+    }
+}
+
+#[test]
+fn test_bundle_module_metadata() {
+    let project = Project::new("Project".to_string());
+    let _ = project.create_document::<TestModule>();
+    let _ = project.create_document::<TestModule>();
+    let _ = project.create_document::<MinimalTestModule>();
+
+    let bundle = project.export_bundle().unwrap();
+
+    let mut expected = vec![
+        ModuleInfo {
+            uuid: TestModule::uuid(),
+            name: TestModule::name(),
+            version: TestModule::version(),
+        },
+        ModuleInfo {
+            uuid: MinimalTestModule::uuid(),
+            name: MinimalTestModule::name(),
+            version: MinimalTestModule::version(),
+        },
+    ];
+    expected.sort();
+    assert_eq!(bundle.modules(), expected);
+}
+
+#[test]
+fn test_bundle_missing_modules() {
+    let project = Project::new("Project".to_string());
+    let _ = project.create_document::<TestModule>();
+    let _ = project.create_document::<MinimalTestModule>();
+    let bundle = project.export_bundle().unwrap();
+
+    let mut registry = ModuleRegistry::default();
+    registry.register::<TestModule>();
+
+    let missing = vec![ModuleInfo {
+        uuid: MinimalTestModule::uuid(),
+        name: MinimalTestModule::name(),
+        version: MinimalTestModule::version(),
+    }];
+    assert_eq!(bundle.missing_modules(&registry), missing);
+    assert_eq!(
+        bundle.open(&registry).unwrap_err(),
+        BundleError::MissingModules(missing)
+    );
+}
+
+#[test]
+fn test_bundle_round_trip() {
+    let project = Project::new("Project".to_string());
+    let doc_uuid = project.create_document::<TestModule>();
+    let mut session = project.open_document::<TestModule>(doc_uuid).unwrap();
+    session
+        .apply(TransactionArgs::Document(TestTransaction::SetWord(
+            "bundled".to_string(),
+        )))
+        .unwrap();
+
+    // Bundles can be stored like any other serializable data
+    let json = serde_json::to_string(&project.export_bundle().unwrap()).unwrap();
+    let bundle: project::bundle::Bundle = serde_json::from_str(&json).unwrap();
+
+    let mut registry = ModuleRegistry::default();
+    registry.register::<TestModule>();
+    let opened = bundle.open(&registry).unwrap();
+
+    let session = opened.open_document::<TestModule>(doc_uuid).unwrap();
+    assert_eq!(session.snapshot().document.single_word, "bundled");
+}
+
+#[test]
+fn test_bundle_module_version() {
+    let project = Project::new("Project".to_string());
+    let _ = project.create_document::<MinimalTestModuleV1>();
+    let bundle = project.export_bundle().unwrap();
+    let info = ModuleInfo {
+        uuid: MinimalTestModuleV1::uuid(),
+        name: MinimalTestModuleV1::name(),
+        version: 1,
+    };
+    assert_eq!(bundle.modules(), std::slice::from_ref(&info));
+
+    // An older version of the module is not enough to open the documents
+    let mut registry = ModuleRegistry::default();
+    registry.register::<MinimalTestModule>();
+    assert_eq!(registry.version(MinimalTestModule::uuid()), Some(0));
+    assert_eq!(
+        bundle.missing_modules(&registry),
+        std::slice::from_ref(&info)
+    );
+    let error = bundle.open(&registry).unwrap_err();
+    assert_eq!(error, BundleError::MissingModules(vec![info]));
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "missing modules: {} v1 ({})",
+            MinimalTestModule::name(),
+            MinimalTestModule::uuid()
+        )
+    );
+
+    let mut registry = ModuleRegistry::default();
+    registry.register::<MinimalTestModuleV1>();
+    assert!(bundle.missing_modules(&registry).is_empty());
+    assert!(bundle.open(&registry).is_ok());
+}
+
+#[test]
+fn test_bundle_open_partial() {
+    let project = Project::new("Project".to_string());
+    let doc_uuid = project.create_document::<TestModule>();
+    let mut session = project.open_document::<TestModule>(doc_uuid).unwrap();
+    session
+        .apply(TransactionArgs::Document(TestTransaction::SetWord(
+            "kept".to_string(),
+        )))
+        .unwrap();
+    drop(session);
+    let mut skipped = vec![
+        project.create_document::<MinimalTestModule>(),
+        project.create_document::<MinimalTestModule>(),
+    ];
+    skipped.sort();
+    let bundle = project.export_bundle().unwrap();
+
+    let mut registry = ModuleRegistry::default();
+    registry.register::<TestModule>();
+    let (opened, missing) = bundle.open_partial(&registry).unwrap();
+    assert_eq!(missing, skipped);
+    assert_eq!(opened.iter_documents().count(), 1);
+    let session = opened.open_document::<TestModule>(doc_uuid).unwrap();
+    assert_eq!(session.snapshot().document.single_word, "kept");
+
+    // With all modules registered, nothing is left out
+    registry.register::<MinimalTestModule>();
+    let (opened, missing) = bundle.open_partial(&registry).unwrap();
+    assert!(missing.is_empty());
+    assert_eq!(opened.iter_documents().count(), 3);
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use transaction::ReversibleDocumentTransaction;
 use uuid::Uuid;
 
-#[derive(Clone, Default, Debug, PartialEq)]
+#[derive(Clone, Default, Debug, PartialEq, Deserialize)]
 pub struct MinimalTestModule {}
 
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
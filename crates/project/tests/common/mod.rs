@@ -1,2 +1,5 @@
+// Not every test uses every item of the common module
+#![allow(dead_code)]
+
 pub mod minimal_test_module;
 pub mod test_module;
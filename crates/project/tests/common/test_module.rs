@@ -101,6 +101,13 @@ impl DocumentTransaction for TestDataSection {
             TestTransaction::FailIfNumberIsOver100 => "Fail if number is over 100".to_string(),
         }
     }
+
+    fn undo_steps(args: &Self::Args) -> Vec<String> {
+        match args {
+            TestTransaction::SetWord(word) => vec!["Set word".to_string(), format!("Word: {word}")],
+            _ => vec![Self::undo_history_name(args)],
+        }
+    }
 }
 
 impl ReversibleDocumentTransaction for TestDataSection {
@@ -1,6 +1,7 @@
 mod common;
 use common::minimal_test_module::*;
 use common::test_module::*;
+use project::document::Module;
 use project::*;
 use uuid::Uuid;
 
@@ -31,3 +32,64 @@ fn test_attempt_open_document_with_incorrect_module() {
     let doc = project.open_document::<TestModule>(doc_uuid);
     assert!(doc.is_none());
 }
+
+#[test]
+fn test_try_open_document_errors() {
+    let project = Project::new("Project".to_string());
+    let doc_uuid = project.create_document::<MinimalTestModule>();
+
+    let missing_uuid = Uuid::new_v4();
+    assert_eq!(
+        project
+            .try_open_document::<TestModule>(missing_uuid)
+            .unwrap_err(),
+        OpenDocumentError::NotFound(missing_uuid)
+    );
+    assert_eq!(
+        project
+            .try_open_document::<TestModule>(doc_uuid)
+            .unwrap_err(),
+        OpenDocumentError::ModuleMismatch {
+            expected: TestModule::uuid(),
+            actual: MinimalTestModule::uuid(),
+        }
+    );
+    assert!(project
+        .try_open_document::<MinimalTestModule>(doc_uuid)
+        .is_ok());
+
+    let error: Box<dyn std::error::Error> = Box::new(
+        project
+            .try_open_document::<TestModule>(missing_uuid)
+            .unwrap_err(),
+    );
+    assert_eq!(
+        error.to_string(),
+        format!("document {missing_uuid} not found")
+    );
+}
+
+#[test]
+fn test_iter_documents() {
+    let project = Project::new("Project".to_string());
+    assert_eq!(project.iter_documents().count(), 0);
+
+    let doc1 = project.create_document::<TestModule>();
+    let doc2 = project.create_document::<MinimalTestModule>();
+    let _session = project.open_document::<TestModule>(doc1).unwrap();
+
+    let mut expected = vec![
+        DocumentInfo {
+            uuid: doc1,
+            module: TestModule::uuid(),
+            has_open_sessions: true,
+        },
+        DocumentInfo {
+            uuid: doc2,
+            module: MinimalTestModule::uuid(),
+            has_open_sessions: false,
+        },
+    ];
+    expected.sort_by_key(|info| info.uuid);
+    assert_eq!(project.iter_documents().collect::<Vec<_>>(), expected);
+}
@@ -0,0 +1,204 @@
+mod common;
+use common::minimal_test_module::*;
+
+use document::{MigrationError, Module};
+use project::transaction::{DocumentTransaction, ReversibleDocumentTransaction};
+use project::*;
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Serialize};
+use utils::Transaction;
+use uuid::Uuid;
+
+/// A second version of [`MinimalTestModule`], which added a label to its data.
+#[derive(Clone, Default, Debug, PartialEq, Deserialize)]
+struct MinimalTestModuleV1 {}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+struct LabeledDataSection {
+    num: i32,
+    label: String,
+}
+
+impl DocumentTransaction for LabeledDataSection {
+    type Args = i32;
+    type Error = ();
+    type Output = ();
+
+    fn apply(&mut self, args: Self::Args) -> Result<Self::Output, Self::Error> {
+        self.num = args;
+        Ok(())
+    }
+
+    fn undo_history_name(args: &Self::Args) -> String {
+        format!("Set num to {args}")
+    }
+}
+
+impl ReversibleDocumentTransaction for LabeledDataSection {
+    type UndoData = i32;
+    fn apply(&mut self, args: Self::Args) -> Result<(Self::Output, Self::UndoData), Self::Error> {
+        let old_num = self.num;
+        self.num = args;
+        Ok(((), old_num))
+    }
+    fn undo(&mut self, undo_data: Self::UndoData) {
+        self.num = undo_data;
+    }
+}
+
+impl LabeledDataSection {
+    fn migrate(version: u32, data: serde_json::Value) -> Result<Self, MigrationError> {
+        if version != 0 {
+            return Err(MigrationError::UnsupportedVersion(version));
+        }
+        let old: TestDataSection =
+            serde_json::from_value(data).map_err(|e| MigrationError::InvalidData(e.to_string()))?;
+        Ok(Self {
+            num: old.num,
+            label: "migrated".to_string(),
+        })
+    }
+}
+
+impl Module for MinimalTestModuleV1 {
+    type DocumentData = LabeledDataSection;
+    type UserData = LabeledDataSection;
+    type SessionData = LabeledDataSection;
+    type SharedData = LabeledDataSection;
+
+    fn name() -> String {
+        MinimalTestModule::name()
+    }
+    fn uuid() -> Uuid {
+        MinimalTestModule::uuid()
+    }
+    fn version() -> u32 {
+        1
+    }
+    fn migrate_document_data(
+        version: u32,
+        data: serde_json::Value,
+    ) -> Result<Self::DocumentData, MigrationError> {
+        LabeledDataSection::migrate(version, data)
+    }
+    fn migrate_user_data(
+        version: u32,
+        data: serde_json::Value,
+    ) -> Result<Self::UserData, MigrationError> {
+        LabeledDataSection::migrate(version, data)
+    }
+}
+
+fn registry<M: Module + for<'de> Deserialize<'de>>() -> ModuleRegistry {
+    let mut registry = ModuleRegistry::default();
+    registry.register::<M>();
+    registry
+}
+
+#[test]
+fn test_migrate_module_data() {
+    let project = Project::new("Project".to_string());
+    let doc_uuid = project.create_document::<MinimalTestModule>();
+    {
+        let mut doc = project
+            .open_document::<MinimalTestModule>(doc_uuid)
+            .unwrap();
+        doc.apply(document::transaction::TransactionArgs::Document(5))
+            .unwrap();
+    }
+    let json = serde_json::to_string(&project).unwrap();
+
+    let project = ProjectSeed {
+        registry: &registry::<MinimalTestModuleV1>(),
+    }
+    .deserialize(&mut serde_json::Deserializer::from_str(&json))
+    .unwrap();
+    let doc = project
+        .open_document::<MinimalTestModuleV1>(doc_uuid)
+        .unwrap();
+    let snapshot = doc.snapshot();
+    assert_eq!(
+        snapshot.document,
+        LabeledDataSection {
+            num: 5,
+            label: "migrated".to_string()
+        }
+    );
+    assert_eq!(snapshot.user.label, "migrated");
+
+    // Data of a newer version can not be opened by an older module
+    let json = serde_json::to_string(&project).unwrap();
+    assert!(ProjectSeed {
+        registry: &registry::<MinimalTestModule>(),
+    }
+    .deserialize(&mut serde_json::Deserializer::from_str(&json))
+    .is_err());
+}
+
+#[test]
+fn test_migrate_module_data_binary() {
+    let project = Project::new("Project".to_string());
+    let doc_uuid = project.create_document::<MinimalTestModule>();
+    {
+        let mut doc = project
+            .open_document::<MinimalTestModule>(doc_uuid)
+            .unwrap();
+        doc.apply(document::transaction::TransactionArgs::Document(7))
+            .unwrap();
+    }
+    let mut binary = vec![];
+    project.write_binary(&mut binary).unwrap();
+
+    let project = ProjectSeed {
+        registry: &registry::<MinimalTestModuleV1>(),
+    }
+    .read_binary(binary.as_slice())
+    .unwrap();
+    let doc = project
+        .open_document::<MinimalTestModuleV1>(doc_uuid)
+        .unwrap();
+    assert_eq!(
+        doc.snapshot().document,
+        LabeledDataSection {
+            num: 7,
+            label: "migrated".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_version_after_module_data() {
+    let project = Project::new("Project".to_string());
+    let doc_uuid = project.create_document::<MinimalTestModuleV1>();
+    {
+        let mut doc = project
+            .open_document::<MinimalTestModuleV1>(doc_uuid)
+            .unwrap();
+        doc.apply(document::transaction::TransactionArgs::Document(3))
+            .unwrap();
+    }
+    // Move the version behind the module data
+    let json = serde_json::to_string(&project).unwrap();
+    assert!(json.contains("\"version\":1,"));
+    let json = json
+        .replace("\"version\":1,", "")
+        .replace("\"module_uuid\":", "\"version\":1,\"module_uuid\":");
+    assert!(json.find("\"version\"").unwrap() > json.find("\"document_data\"").unwrap());
+
+    let project = ProjectSeed {
+        registry: &registry::<MinimalTestModuleV1>(),
+    }
+    .read_json(json.as_bytes())
+    .unwrap();
+    let doc = project
+        .open_document::<MinimalTestModuleV1>(doc_uuid)
+        .unwrap();
+    // The data is read with its actual version instead of being migrated from version 0
+    assert_eq!(
+        doc.snapshot().document,
+        LabeledDataSection {
+            num: 3,
+            label: String::new()
+        }
+    );
+}
@@ -1,10 +1,13 @@
 mod common;
 
+use common::minimal_test_module::{self, MinimalTestModule};
 use common::test_module::*;
 
+use document::Module;
 use project::document::transaction::TransactionArgs;
 use project::*;
 use serde::de::DeserializeSeed;
+use serde::Deserialize;
 use utils::Transaction;
 
 #[test]
@@ -65,3 +68,221 @@ fn test_serde_project_json() {
         );
     }
 }
+
+#[test]
+fn test_serializable_guard() {
+    let project = Project::new("Project".to_string());
+    let doc_uuid = project.create_document::<TestModule>();
+    assert!(project.serializable().is_ok());
+
+    {
+        let mut doc = project.open_document::<TestModule>(doc_uuid).unwrap();
+        assert!(doc
+            .apply(TransactionArgs::Session(TestTransaction::SetWord(
+                "Test".to_string()
+            )))
+            .is_ok());
+        // The session data would be lost
+        let error = project.serializable().unwrap_err();
+        assert_eq!(error, SerializeError::OpenSessions(vec![doc_uuid]));
+        let error: Box<dyn std::error::Error> = Box::new(error);
+        assert_eq!(
+            error.to_string(),
+            format!("documents with open sessions: {doc_uuid}")
+        );
+    }
+
+    let json = serde_json::to_string(project.serializable().unwrap()).unwrap();
+    assert!(!json.is_empty());
+}
+
+#[test]
+fn test_serializable_closed_session_history() {
+    let project = Project::new("Project".to_string());
+    let doc_uuid = project.create_document::<TestModule>();
+    {
+        let mut doc = project.open_document::<TestModule>(doc_uuid).unwrap();
+        assert!(doc
+            .apply(TransactionArgs::Document(TestTransaction::SetWord(
+                "Test".to_string()
+            )))
+            .is_ok());
+    }
+
+    // The history of the closed session is kept in memory, but not serialized
+    let doc = project.open_document::<TestModule>(doc_uuid).unwrap();
+    assert_eq!(doc.transaction_log().len(), 1);
+    drop(doc);
+    let json = serde_json::to_string(project.serializable().unwrap()).unwrap();
+
+    let mut registry = ModuleRegistry::default();
+    registry.register::<TestModule>();
+    let project = ProjectSeed {
+        registry: &registry,
+    }
+    .read_json(json.as_bytes())
+    .unwrap();
+    let doc = project.open_document::<TestModule>(doc_uuid).unwrap();
+    assert!(doc.transaction_log().is_empty());
+    assert_eq!(doc.snapshot().document.single_word, "Test");
+}
+
+#[test]
+fn test_serde_project_binary() {
+    let doc_uuid;
+    let mut binary = vec![];
+
+    {
+        let project = Project::new("Project".to_string());
+        doc_uuid = project.create_document::<TestModule>();
+        let mut doc = project.open_document::<TestModule>(doc_uuid).unwrap();
+        assert!(doc
+            .apply(TransactionArgs::Document(TestTransaction::SetWord(
+                "Test".to_string()
+            )))
+            .is_ok());
+        assert!(doc
+            .apply(TransactionArgs::User(TestTransaction::SetNumber(7)))
+            .is_ok());
+        project.write_binary(&mut binary).unwrap();
+
+        let json = serde_json::to_string(&project).unwrap();
+        assert!(binary.len() < json.len());
+    }
+
+    let mut registry = ModuleRegistry::default();
+    registry.register::<TestModule>();
+    let project = ProjectSeed {
+        registry: &registry,
+    }
+    .read_binary(binary.as_slice())
+    .unwrap();
+
+    let doc = project.open_document::<TestModule>(doc_uuid).unwrap();
+    let snapshot = doc.snapshot();
+    assert_eq!(snapshot.document.single_word, "Test");
+    assert_eq!(snapshot.user.odd_number, 7);
+
+    // Truncated data is reported as an error
+    let mut truncated = vec![];
+    project.write_binary(&mut truncated).unwrap();
+    truncated.truncate(truncated.len() / 2);
+    assert!(ProjectSeed {
+        registry: &registry,
+    }
+    .read_binary(truncated.as_slice())
+    .is_err());
+
+    // A corrupted length prefix is rejected instead of allocating the claimed size.
+    // No documents, followed by a project name claiming to be 1 TiB long.
+    let mut corrupted = vec![0x00, 0xfd];
+    corrupted.extend_from_slice(&(1u64 << 40).to_le_bytes());
+    let error = ProjectSeed {
+        registry: &registry,
+    }
+    .read_binary(corrupted.as_slice())
+    .unwrap_err();
+    assert!(matches!(error, LoadError::Io(e) if e.kind() == std::io::ErrorKind::InvalidData));
+}
+
+#[test]
+fn test_serde_project_deterministic_ids() {
+    let serialize = || {
+        let project = Project::new("Project".to_string())
+            .with_id_generator(id_generator::SeededIdGenerator::new(42));
+        let doc_uuids = [
+            project.create_document::<MinimalTestModule>(),
+            project.create_document::<MinimalTestModule>(),
+        ];
+        let mut binary = vec![];
+        project.write_binary(&mut binary).unwrap();
+        (doc_uuids, serde_json::to_string(&project).unwrap(), binary)
+    };
+
+    let (doc_uuids, json, binary) = serialize();
+    assert_ne!(doc_uuids[0], doc_uuids[1]);
+    assert_eq!(serialize(), (doc_uuids, json, binary));
+}
+
+/// A module depending on [`MinimalTestModule`].
+#[derive(Clone, Default, Debug, PartialEq, Deserialize)]
+struct DependentModule {}
+
+impl Module for DependentModule {
+    type DocumentData = minimal_test_module::TestDataSection;
+    type UserData = minimal_test_module::TestDataSection;
+    type SessionData = minimal_test_module::TestDataSection;
+    type SharedData = minimal_test_module::TestDataSection;
+
+    fn name() -> String {
+        "Dependent Module".to_string()
+    }
+    fn uuid() -> uuid::Uuid {
+        uuid::Uuid::parse_str("3d8b6d36-4c64-4f3c-9a59-6a2f0a3f8a21").unwrap()
+    }
+    fn dependencies() -> Vec<uuid::Uuid> {
+        vec![MinimalTestModule::uuid()]
+    }
+}
+
+#[test]
+fn test_registry_missing_dependency() {
+    let project = Project::new("Project".to_string());
+    let _ = project.create_document::<DependentModule>();
+    let json = serde_json::to_string(&project).unwrap();
+
+    let mut registry = ModuleRegistry::default();
+    registry.register::<DependentModule>();
+    assert_eq!(
+        registry.check_dependencies(),
+        Err(RegistryError::MissingDependency {
+            module: DependentModule::uuid(),
+            dependency: MinimalTestModule::uuid(),
+        })
+    );
+    let mut binary = vec![];
+    project.write_binary(&mut binary).unwrap();
+    assert!(matches!(
+        ProjectSeed {
+            registry: &registry
+        }
+        .read_json(json.as_bytes()),
+        Err(LoadError::Registry(RegistryError::MissingDependency { module, dependency }))
+            if module == DependentModule::uuid() && dependency == MinimalTestModule::uuid()
+    ));
+    assert!(matches!(
+        ProjectSeed {
+            registry: &registry
+        }
+        .read_binary(binary.as_slice()),
+        Err(LoadError::Registry(RegistryError::MissingDependency { .. }))
+    ));
+
+    registry.register::<MinimalTestModule>();
+    assert_eq!(registry.check_dependencies(), Ok(()));
+    assert!(ProjectSeed {
+        registry: &registry
+    }
+    .read_json(json.as_bytes())
+    .is_ok());
+}
+
+#[test]
+fn test_registered_modules() {
+    let mut registry = ModuleRegistry::default();
+    assert!(registry.registered_modules().is_empty());
+    registry.register::<TestModule>();
+    registry.register::<MinimalTestModule>();
+
+    let mut expected = vec![
+        (TestModule::uuid(), "Test Module".to_string()),
+        (
+            MinimalTestModule::uuid(),
+            "A Minimal Test Module".to_string(),
+        ),
+    ];
+    expected.sort();
+    assert_eq!(registry.registered_modules(), expected);
+    assert!(registry.contains(TestModule::uuid()));
+    assert!(!registry.contains(DependentModule::uuid()));
+}
@@ -68,3 +68,51 @@ fn test_failed_transaction() {
         );
     }
 }
+
+#[test]
+fn test_transaction_log() {
+    let project = Project::new("Project".to_string());
+    let doc_uuid = project.create_document::<TestModule>();
+    let mut session1 = project.open_document::<TestModule>(doc_uuid).unwrap();
+    let mut session2 = project.open_document::<TestModule>(doc_uuid).unwrap();
+
+    session1
+        .apply(TransactionArgs::Document(TestTransaction::SetWord(
+            "a".to_string(),
+        )))
+        .unwrap();
+    session2
+        .apply(TransactionArgs::User(TestTransaction::SetNumber(3)))
+        .unwrap();
+    // Neither rejected nor non-persistent transactions are part of the log
+    assert!(session1
+        .apply(TransactionArgs::Document(TestTransaction::SetNumber(2)))
+        .is_err());
+    session1
+        .apply(TransactionArgs::Session(TestTransaction::SetNumber(5)))
+        .unwrap();
+    session1
+        .apply(TransactionArgs::Document(TestTransaction::SetWord(
+            "b".to_string(),
+        )))
+        .unwrap();
+    session1.undo(1);
+
+    let log = session2.transaction_log();
+    assert_eq!(log, session1.transaction_log());
+    assert_eq!(
+        log.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(),
+        vec!["Set word to a", "Set number to 3", "Set word to b"]
+    );
+    assert_eq!(log[0].session, log[2].session);
+    assert_ne!(log[0].session, log[1].session);
+    assert!(log.iter().all(|r| r.user == user::User::local()));
+    assert_eq!(
+        log.iter().map(|r| r.state).collect::<Vec<_>>(),
+        vec![
+            document::TransactionRecordState::Applied,
+            document::TransactionRecordState::Applied,
+            document::TransactionRecordState::Undone
+        ]
+    );
+}
@@ -714,3 +714,96 @@ fn test_undo_redo_on_failed_transactions() {
     );
     assert_eq!(get_user_log_and_clear(), vec![]);
 }
+
+#[test]
+fn test_undo_redo_steps() {
+    let (_project, mut session1, _session2, _doc_uuid, _transactions) =
+        create_undo_redo_test_setup();
+    session1.undo(1);
+
+    let (steps, position) = session1.undo_redo_steps();
+    assert_eq!(
+        steps,
+        vec![
+            vec!["Set word".to_string(), "Word: word_a".to_string()],
+            vec!["Set word".to_string(), "Word: word_b".to_string()],
+            vec!["Set word".to_string(), "Word: word_e".to_string()],
+        ]
+    );
+    assert_eq!(position, 2);
+    assert_eq!(session1.undo_redo_list().1, position);
+}
+
+#[test]
+fn test_undo_is_scoped_to_document() {
+    let project = Project::new("Project".to_string());
+    let doc_a = project.create_document::<TestModule>();
+    let doc_b = project.create_document::<TestModule>();
+    let mut session_a = project.open_document::<TestModule>(doc_a).unwrap();
+    let mut session_b = project.open_document::<TestModule>(doc_b).unwrap();
+
+    session_a
+        .apply(TransactionArgs::Document(TestTransaction::SetWord(
+            "a".to_string(),
+        )))
+        .unwrap();
+    session_b
+        .apply(TransactionArgs::Document(TestTransaction::SetWord(
+            "b".to_string(),
+        )))
+        .unwrap();
+
+    // Undoing in document A must not revert the later change to document B
+    session_a.undo(1);
+    assert_eq!(session_a.snapshot().document.single_word, "default");
+    assert_eq!(session_b.snapshot().document.single_word, "b");
+
+    // And a second session on document B sees only its own document's history
+    let session_b2 = project.open_document::<TestModule>(doc_b).unwrap();
+    assert_eq!(session_b2.transaction_log().len(), 1);
+    assert_eq!(
+        session_a.undo_redo_list(),
+        (vec!["Set word to a".to_string()], 0)
+    );
+
+    session_b.undo(1);
+    assert_eq!(session_b.snapshot().document.single_word, "default");
+    session_a.redo(1);
+    assert_eq!(session_a.snapshot().document.single_word, "a");
+    assert_eq!(session_b2.snapshot().document.single_word, "default");
+}
+
+#[test]
+fn test_shared_transactions_are_not_undoable() {
+    let project = Project::new("Project".to_string());
+    let doc_uuid = project.create_document::<TestModule>();
+    let mut session1 = project.open_document::<TestModule>(doc_uuid).unwrap();
+    let session2 = project.open_document::<TestModule>(doc_uuid).unwrap();
+
+    session1
+        .apply(TransactionArgs::Document(TestTransaction::SetWord(
+            "a".to_string(),
+        )))
+        .unwrap();
+    session1
+        .apply(TransactionArgs::Shared(TestTransaction::SetWord(
+            "b".to_string(),
+        )))
+        .unwrap();
+
+    // Only the document transaction is part of the undo history
+    assert_eq!(
+        session1.undo_redo_list(),
+        (vec!["Set word to a".to_string()], 1)
+    );
+    assert_eq!(session1.transaction_log().len(), 1);
+
+    session1.undo(1);
+    for snapshot in [session1.snapshot(), session2.snapshot()] {
+        assert_eq!(snapshot.document.single_word, "default");
+        assert_eq!(
+            snapshot.shared.single_word, "b",
+            "Undo should not revert shared data"
+        );
+    }
+}